@@ -1,7 +1,28 @@
 // Copyright (c) BohuTANG
 // Code is licensed with BSD
 
-use crate::gcd;
+use crate::Integer;
+
+/// Reduces a into [0, m).
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::arith;
+///
+/// fn main() {
+///     let x = arith::reduce(-14, 37);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn reduce<T: Integer>(a: T, m: T) -> T {
+    let r = a % m.clone();
+    if r.is_negative() {
+        r + m
+    } else {
+        r
+    }
+}
 
 /// Computes two numbers sum in modulo arithmetic.
 ///
@@ -9,6 +30,12 @@ use crate::gcd;
 /// a + b (mod m)
 /// ```
 ///
+/// ```text
+/// a + b can overflow the width of T, so compare a against m - b:
+/// a >= m - b: a + b - m = a - (m - b)
+/// otherwise:  a + b < m
+/// ```
+///
 /// # Examples
 ///
 /// ```rust
@@ -19,14 +46,14 @@ use crate::gcd;
 ///     println!("{:?}", x);
 /// }
 /// ```
-pub fn mod_add(a: i8, b: i8, m: i8) -> i8 {
-    let a1 = a % m;
-    let b1 = b % m;
-
-    if b1 > (m - a1) {
-        b1 - (m - a1)
+pub fn mod_add<T: Integer>(a: T, b: T, m: T) -> T {
+    let a = reduce(a, m.clone());
+    let b = reduce(b, m.clone());
+    let t = m - b.clone();
+    if a >= t {
+        a - t
     } else {
-        (a1 + b1 + m) % m
+        a + b
     }
 }
 
@@ -46,8 +73,14 @@ pub fn mod_add(a: i8, b: i8, m: i8) -> i8 {
 ///     println!("{:?}", x);
 /// }
 /// ```
-pub fn mod_sub(a: i8, b: i8, m: i8) -> i8 {
-    mod_add(a, -b, m)
+pub fn mod_sub<T: Integer>(a: T, b: T, m: T) -> T {
+    let a = reduce(a, m.clone());
+    let b = reduce(b, m.clone());
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
 }
 
 /// Computes two numbers product in modulo arithmetic.
@@ -56,6 +89,14 @@ pub fn mod_sub(a: i8, b: i8, m: i8) -> i8 {
 /// a * b (mod m)
 /// ```
 ///
+/// ```text
+/// Fixed-width types double-and-add, scanning b from the most significant bit:
+/// res = 2 * res (mod m)
+/// res = res + a (mod m), if the bit is 1
+/// Only mod_add is used, so a * b never overflows the width of T.
+/// BigUint and BigInt compute (a * b) % m directly, see Integer::mul_mod.
+/// ```
+///
 /// # Examples
 ///
 /// ```rust
@@ -66,12 +107,10 @@ pub fn mod_sub(a: i8, b: i8, m: i8) -> i8 {
 ///     println!("{:?}", x);
 /// }
 /// ```
-pub fn mod_mul(a: i8, b: i8, m: i8) -> i8 {
-    let mut res = 0;
-    for _ in 1..=b {
-        res = mod_add(a, res, m);
-    }
-    res
+pub fn mod_mul<T: Integer>(a: T, b: T, m: T) -> T {
+    let a = reduce(a, m.clone());
+    let b = reduce(b, m.clone());
+    a.mul_mod(&b, &m)
 }
 
 /// Computes two numbers division in modulo arithmetic.
@@ -90,8 +129,8 @@ pub fn mod_mul(a: i8, b: i8, m: i8) -> i8 {
 ///     println!("{:?}", x);
 /// }
 /// ```
-pub fn mod_div(a: i8, b: i8, m: i8) -> i8 {
-    let inv = mod_inv(b, m);
+pub fn mod_div<T: Integer>(a: T, b: T, m: T) -> T {
+    let inv = mod_inv(b, m.clone());
     mod_mul(a, inv, m)
 }
 
@@ -107,6 +146,12 @@ pub fn mod_div(a: i8, b: i8, m: i8) -> i8 {
 /// a * x == 1 (mod m)
 /// a * x + m * y == 1 (mod m)
 /// (g,x,y)= xgcd(a,m)
+///
+/// Only the coefficient of a is needed and it is kept reduced mod m,
+/// so unsigned types work as well:
+/// r(i) == s(i) * a (mod m)
+/// r(i+1) = r(i-1) - q * r(i)
+/// s(i+1) = s(i-1) - q * s(i) (mod m)
 /// ```
 ///
 /// # Examples
@@ -119,16 +164,35 @@ pub fn mod_div(a: i8, b: i8, m: i8) -> i8 {
 ///     println!("{:?}", x);
 /// }
 /// ```
-pub fn mod_inv(a: i8, m: i8) -> i8 {
-    let (g, x, _) = gcd::xgcd(a, m);
-    assert!(g == 1);
-    (x + m) % m
+pub fn mod_inv<T: Integer>(a: T, m: T) -> T {
+    let (mut r_last, mut r) = (m.clone(), reduce(a, m.clone()));
+    let (mut s_last, mut s) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let quotient = r_last.clone() / r.clone();
+        let r_next = r_last % r.clone();
+        let s_next = mod_sub(s_last, mod_mul(quotient, s.clone(), m.clone()), m.clone());
+        r_last = r;
+        r = r_next;
+        s_last = s;
+        s = s_next;
+    }
+    assert!(r_last.is_one());
+    reduce(s_last, m)
 }
 
 /// Computes exponention in modulo arithmetic.
 ///
 /// ```text
 /// a ^ b (mod m)
+/// a ^ 0 = 1 (mod m)
+/// a ^ -b = (a ^ -1) ^ b (mod m)
+/// ```
+///
+/// ```text
+/// Left-to-right binary (square-and-multiply):
+/// res = res ^ 2 (mod m)
+/// res = res * a (mod m), if the bit is 1
 /// ```
 ///
 /// # Examples
@@ -141,10 +205,92 @@ pub fn mod_inv(a: i8, m: i8) -> i8 {
 ///     println!("{:?}", x);
 /// }
 /// ```
-pub fn mod_exp(base: i8, exponent: i8, m: i8) -> i8 {
-    let mut res = base;
-    for _ in 1..exponent {
-        res = mod_mul(res, base, m);
+pub fn mod_exp<T: Integer>(base: T, exponent: T, m: T) -> T {
+    let (base, exponent, tail) = exp_operands(base, exponent, m.clone());
+    let mut res = reduce(T::one(), m.clone());
+    for i in (0..exponent.bits()).rev() {
+        res = mod_mul(res.clone(), res, m.clone());
+        if exponent.bit(i) {
+            res = mod_mul(res, base.clone(), m.clone());
+        }
+    }
+    mod_mul(res, tail, m)
+}
+
+/// Computes exponention in modulo arithmetic with a sliding window.
+///
+/// ```text
+/// a ^ b (mod m)
+/// ```
+///
+/// ```text
+/// Precompute the odd powers a, a^3, ..., a^(2^w - 1).
+/// Scan b from the most significant bit, zero bits are squared alone,
+/// otherwise take the longest window (at most w bits) ending in a 1 bit:
+/// res = res ^ (2^len) * a^window (mod m)
+/// About b.bits() squarings and b.bits()/(w+1) multiplications.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::arith;
+///
+/// fn main() {
+///     let x = arith::mod_exp_window(3, 1000, 37, 4);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn mod_exp_window<T: Integer>(base: T, exponent: T, m: T, window: usize) -> T {
+    assert!(window > 0);
+    let (base, exponent, tail) = exp_operands(base, exponent, m.clone());
+
+    // odd[k] = a^(2k+1)
+    let base2 = mod_mul(base.clone(), base.clone(), m.clone());
+    let mut odd = vec![base];
+    for k in 1..(1usize << (window - 1)) {
+        let next = mod_mul(odd[k - 1].clone(), base2.clone(), m.clone());
+        odd.push(next);
+    }
+
+    let mut res = reduce(T::one(), m.clone());
+    let mut i = exponent.bits();
+    while i > 0 {
+        if !exponent.bit(i - 1) {
+            res = mod_mul(res.clone(), res, m.clone());
+            i -= 1;
+            continue;
+        }
+
+        // Window covers bits [l, i), its lowest bit is 1.
+        let mut l = i.saturating_sub(window);
+        while !exponent.bit(l) {
+            l += 1;
+        }
+        let mut value = 0usize;
+        for j in (l..i).rev() {
+            res = mod_mul(res.clone(), res, m.clone());
+            value = (value << 1) | exponent.bit(j) as usize;
+        }
+        res = mod_mul(res, odd[value >> 1].clone(), m.clone());
+        i = l;
+    }
+    mod_mul(res, tail, m)
+}
+
+/// Reduces the base and turns a negative exponent into an inverse.
+///
+/// ```text
+/// Returns (b, e, t) with a^exponent = b^e * t (mod m).
+/// exponent < 0: b = a^-1, e = -(exponent + 1), t = a^-1
+/// -(exponent + 1) never overflows, -exponent does for T::MIN.
+/// ```
+fn exp_operands<T: Integer>(base: T, exponent: T, m: T) -> (T, T, T) {
+    let base = reduce(base, m.clone());
+    if exponent.is_negative() {
+        let inv = mod_inv(base, m);
+        (inv.clone(), T::zero() - (exponent + T::one()), inv)
+    } else {
+        (base, exponent, reduce(T::one(), m))
     }
-    res
 }
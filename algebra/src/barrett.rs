@@ -0,0 +1,123 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::bigint::BigUint;
+
+/// Barrett reduction for a fixed modulus.
+///
+/// ```text
+/// b = 2^32 (limb base), k = limbs of m
+/// mu = floor(b^2k / m) is computed once, after that every x < b^2k
+/// is reduced with multiplications and shifts only, no division by m.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::bigint::BigUint;
+/// use algebra::barrett::BarrettReducer;
+///
+/// fn main() {
+///     let br = BarrettReducer::new(&BigUint::from(37u32));
+///     let x = br.mul(&BigUint::from(14u32), &BigUint::from(35u32));
+///     println!("{}", x);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarrettReducer {
+    m: BigUint,
+    k: usize,
+    mu: BigUint,
+}
+
+/// x mod b^n, keeps the lowest n limbs.
+fn low_limbs(x: &BigUint, n: usize) -> BigUint {
+    let limbs = x.limbs();
+    BigUint::from_limbs(&limbs[..limbs.len().min(n)])
+}
+
+impl BarrettReducer {
+    /// Precomputes mu = floor(b^2k / m).
+    pub fn new(m: &BigUint) -> Self {
+        assert!(!m.is_zero(), "Barrett modulus must not be zero");
+        let k = m.limbs().len();
+        let mu = (BigUint::one() << (64 * k)) / m;
+        BarrettReducer {
+            m: m.clone(),
+            k,
+            mu,
+        }
+    }
+
+    /// Returns the modulus m.
+    pub fn modulus(&self) -> &BigUint {
+        &self.m
+    }
+
+    /// Returns mu = floor(b^2k / m).
+    pub fn mu(&self) -> &BigUint {
+        &self.mu
+    }
+
+    /// Computes x mod m for x < b^2k.
+    ///
+    /// ```text
+    /// Algorithm (HAC 14.42):
+    /// q = floor(floor(x / b^(k-1)) * mu / b^(k+1))
+    /// r = (x mod b^(k+1)) - (q * m mod b^(k+1))
+    /// if r < 0: r = r + b^(k+1)
+    /// while r >= m: r = r - m (at most twice)
+    /// ```
+    pub fn reduce(&self, x: &BigUint) -> BigUint {
+        assert!(
+            x.limbs().len() <= 2 * self.k,
+            "Barrett input must be less than b^2k"
+        );
+        let k = self.k;
+        let q1 = x >> (32 * (k - 1));
+        let q3 = (&q1 * &self.mu) >> (32 * (k + 1));
+
+        let r1 = low_limbs(x, k + 1);
+        let r2 = low_limbs(&(&q3 * &self.m), k + 1);
+        let mut r = if r1 >= r2 {
+            r1 - r2
+        } else {
+            (BigUint::one() << (32 * (k + 1))) + r1 - r2
+        };
+        while r >= self.m {
+            r = r - &self.m;
+        }
+        r
+    }
+
+    /// Computes a * b mod m for a, b less than m.
+    pub fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        self.reduce(&(a * b))
+    }
+
+    /// Computes base ^ exponent mod m with square-and-multiply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use algebra::bigint::BigUint;
+    /// use algebra::barrett::BarrettReducer;
+    ///
+    /// fn main() {
+    ///     let br = BarrettReducer::new(&BigUint::from(37u32));
+    ///     let x = br.exp(&BigUint::from(18u32), &BigUint::from(11u32));
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    pub fn exp(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let base = self.reduce(base);
+        let mut res = self.reduce(&BigUint::one());
+        for i in (0..exponent.bits()).rev() {
+            res = self.mul(&res, &res);
+            if exponent.bit(i) {
+                res = self.mul(&res, &base);
+            }
+        }
+        res
+    }
+}
@@ -0,0 +1,859 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Shl, Shr, Sub};
+use std::str::FromStr;
+
+const LIMB_BITS: usize = 32;
+
+/// Arbitrary-precision unsigned integer.
+///
+/// ```text
+/// The magnitude is stored as little-endian 32-bit limbs:
+/// n = limbs[0] + limbs[1] * 2^32 + limbs[2] * 2^64 + ...
+/// There are no leading zero limbs, so zero is the empty vector.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::bigint::BigUint;
+///
+/// fn main() {
+///     let a: BigUint = "340282366920938463463374607431768211457".parse().unwrap();
+///     let b = BigUint::from(37u32);
+///     println!("{}", &a % &b);
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+/// Arbitrary-precision signed integer.
+///
+/// ```text
+/// Sign-magnitude representation, zero is never negative.
+/// Division truncates toward zero and the remainder takes the sign
+/// of the dividend, the same as the primitive integer types.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::bigint::BigInt;
+///
+/// fn main() {
+///     let a = BigInt::from(-14);
+///     let b = BigInt::from(37);
+///     println!("{}", (a % &b + &b) % b);
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+/// Error returned when parsing a big integer from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid big integer literal")
+    }
+}
+
+/// Error returned when a big integer does not fit the target type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryFromBigIntError;
+
+impl fmt::Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "big integer out of range")
+    }
+}
+
+fn normalize(limbs: &mut Vec<u32>) {
+    while let Some(&0) = limbs.last() {
+        limbs.pop();
+    }
+}
+
+fn cmp_limbs(a: &[u32], b: &[u32]) -> Ordering {
+    if a.len() != b.len() {
+        return a.len().cmp(&b.len());
+    }
+    for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+        if x != y {
+            return x.cmp(y);
+        }
+    }
+    Ordering::Equal
+}
+
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &x) in long.iter().enumerate() {
+        let s = x as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        out.push(s as u32);
+        carry = s >> LIMB_BITS;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+/// Requires a >= b.
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &x) in a.iter().enumerate() {
+        let mut d = x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        if d < 0 {
+            d += 1 << LIMB_BITS;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        out.push(d as u32);
+    }
+    assert!(borrow == 0, "BigUint subtraction underflow");
+    normalize(&mut out);
+    out
+}
+
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let t = x as u64 * y as u64 + out[i + j] as u64 + carry;
+            out[i + j] = t as u32;
+            carry = t >> LIMB_BITS;
+        }
+        out[i + b.len()] = carry as u32;
+    }
+    normalize(&mut out);
+    out
+}
+
+fn shl_limbs(a: &[u32], n: usize) -> Vec<u32> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let (words, bits) = (n / LIMB_BITS, n % LIMB_BITS);
+    let mut out = vec![0u32; words];
+    if bits == 0 {
+        out.extend_from_slice(a);
+    } else {
+        let mut carry = 0u32;
+        for &x in a {
+            out.push((x << bits) | carry);
+            carry = x >> (LIMB_BITS - bits);
+        }
+        if carry > 0 {
+            out.push(carry);
+        }
+    }
+    out
+}
+
+fn shr_limbs(a: &[u32], n: usize) -> Vec<u32> {
+    let (words, bits) = (n / LIMB_BITS, n % LIMB_BITS);
+    if words >= a.len() {
+        return Vec::new();
+    }
+    let mut out: Vec<u32> = a[words..].to_vec();
+    if bits > 0 {
+        for i in 0..out.len() {
+            let hi = out.get(i + 1).map_or(0, |&x| x << (LIMB_BITS - bits));
+            out[i] = (out[i] >> bits) | hi;
+        }
+    }
+    normalize(&mut out);
+    out
+}
+
+/// Divides by a single limb, returns (quotient, remainder).
+fn divrem_small(u: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let d = d as u64;
+    let mut q = vec![0u32; u.len()];
+    let mut rem = 0u64;
+    for i in (0..u.len()).rev() {
+        let cur = (rem << LIMB_BITS) | u[i] as u64;
+        q[i] = (cur / d) as u32;
+        rem = cur % d;
+    }
+    normalize(&mut q);
+    (q, rem as u32)
+}
+
+/// Knuth's Algorithm D (TAOCP Vol.2 4.3.1), returns (quotient, remainder).
+///
+/// ```text
+/// Requires v.len() >= 2.
+/// Normalize so the top bit of v is set, then estimate every quotient limb
+/// from the top two limbs of the running remainder. The estimate is at most
+/// two too large, the final correction step adds the divisor back once.
+/// ```
+fn divrem_knuth(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let b = 1u64 << LIMB_BITS;
+    let s = v[v.len() - 1].leading_zeros() as usize;
+    let vn = shl_limbs(v, s);
+    let mut un = shl_limbs(u, s);
+    if un.len() == u.len() {
+        un.push(0);
+    }
+
+    let n = vn.len();
+    let m = un.len() - n;
+    let mut q = vec![0u32; m];
+    for j in (0..m).rev() {
+        let num = ((un[j + n] as u64) << LIMB_BITS) | un[j + n - 1] as u64;
+        let mut qhat = num / vn[n - 1] as u64;
+        let mut rhat = num % vn[n - 1] as u64;
+        while qhat >= b || qhat * vn[n - 2] as u64 > ((rhat << LIMB_BITS) | un[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= b {
+                break;
+            }
+        }
+
+        // un[j..j+n] -= qhat * vn
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let p = qhat * vn[i] as u64 + carry;
+            carry = p >> LIMB_BITS;
+            let t = un[i + j] as i64 - borrow - (p & 0xffff_ffff) as i64;
+            un[i + j] = t as u32;
+            borrow = if t < 0 { 1 } else { 0 };
+        }
+        let t = un[j + n] as i64 - borrow - carry as i64;
+        un[j + n] = t as u32;
+
+        // Estimate was one too large, add back.
+        if t < 0 {
+            qhat -= 1;
+            let mut c = 0u64;
+            for i in 0..n {
+                let s = un[i + j] as u64 + vn[i] as u64 + c;
+                un[i + j] = s as u32;
+                c = s >> LIMB_BITS;
+            }
+            un[j + n] = un[j + n].wrapping_add(c as u32);
+        }
+        q[j] = qhat as u32;
+    }
+    normalize(&mut q);
+    un.truncate(n);
+    normalize(&mut un);
+    (q, shr_limbs(&un, s))
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        BigUint { limbs: vec![1] }
+    }
+
+    /// Builds a number from little-endian 32-bit limbs.
+    pub fn from_limbs(limbs: &[u32]) -> Self {
+        let mut limbs = limbs.to_vec();
+        normalize(&mut limbs);
+        BigUint { limbs }
+    }
+
+    /// Returns the little-endian 32-bit limbs.
+    pub fn limbs(&self) -> &[u32] {
+        &self.limbs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.limbs == [1]
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.limbs.first().copied().unwrap_or(0) & 1 == 0
+    }
+
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    /// Returns the number of significant bits, zero has 0 bits.
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            None => 0,
+            Some(top) => self.limbs.len() * LIMB_BITS - top.leading_zeros() as usize,
+        }
+    }
+
+    /// Returns the i-th bit (counting from the least significant bit).
+    pub fn bit(&self, i: usize) -> bool {
+        let limb = self.limbs.get(i / LIMB_BITS).copied().unwrap_or(0);
+        (limb >> (i % LIMB_BITS)) & 1 == 1
+    }
+
+    /// Computes the quotient and remainder in one pass.
+    ///
+    /// ```text
+    /// self = q * d + r, 0 <= r < d
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use algebra::bigint::BigUint;
+    ///
+    /// fn main() {
+    ///     let (q, r) = BigUint::from(100u32).divrem(&BigUint::from(7u32));
+    ///     println!("{},{}", q, r);
+    /// }
+    /// ```
+    pub fn divrem(&self, d: &BigUint) -> (BigUint, BigUint) {
+        assert!(!d.is_zero(), "BigUint division by zero");
+        if cmp_limbs(&self.limbs, &d.limbs) == Ordering::Less {
+            return (BigUint::zero(), self.clone());
+        }
+        if d.limbs.len() == 1 {
+            let (q, r) = divrem_small(&self.limbs, d.limbs[0]);
+            return (BigUint { limbs: q }, BigUint::from(r));
+        }
+        let (q, r) = divrem_knuth(&self.limbs, &d.limbs);
+        (BigUint { limbs: q }, BigUint { limbs: r })
+    }
+
+    /// Computes self ^ exponent without modular reduction.
+    pub fn pow(&self, mut exponent: u32) -> BigUint {
+        let mut base = self.clone();
+        let mut res = BigUint::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                res = &res * &base;
+            }
+            base = &base * &base;
+            exponent >>= 1;
+        }
+        res
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u64),
+            2 => Some(self.limbs[0] as u64 | (self.limbs[1] as u64) << LIMB_BITS),
+            _ => None,
+        }
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        let mut res = 0u128;
+        for &x in self.limbs.iter().rev() {
+            res = (res << LIMB_BITS) | x as u128;
+        }
+        Some(res)
+    }
+
+    /// Parses a number in the given radix (2..=36).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use algebra::bigint::BigUint;
+    ///
+    /// fn main() {
+    ///     let x = BigUint::from_str_radix("ffffffffffffffffffffffff", 16).unwrap();
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        assert!((2..=36).contains(&radix));
+        if s.is_empty() {
+            return Err(ParseBigIntError);
+        }
+        let mut limbs: Vec<u32> = Vec::new();
+        for c in s.chars() {
+            if c == '_' {
+                continue;
+            }
+            let digit = c.to_digit(radix).ok_or(ParseBigIntError)?;
+            // limbs = limbs * radix + digit
+            let mut carry = digit as u64;
+            for x in limbs.iter_mut() {
+                let t = *x as u64 * radix as u64 + carry;
+                *x = t as u32;
+                carry = t >> LIMB_BITS;
+            }
+            if carry > 0 {
+                limbs.push(carry as u32);
+            }
+        }
+        normalize(&mut limbs);
+        Ok(BigUint { limbs })
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_limbs(&self.limbs, &other.limbs)
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+        // Peel off 9 decimal digits at a time.
+        let mut chunks = Vec::new();
+        let mut limbs = self.limbs.clone();
+        while !limbs.is_empty() {
+            let (q, r) = divrem_small(&limbs, 1_000_000_000);
+            chunks.push(r);
+            limbs = q;
+        }
+        let mut s = chunks.pop().unwrap().to_string();
+        for c in chunks.iter().rev() {
+            s.push_str(&format!("{:09}", c));
+        }
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BigUint::from_str_radix(s, 10)
+    }
+}
+
+macro_rules! biguint_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigUint {
+                fn from(n: $t) -> Self {
+                    let mut n = n as u128;
+                    let mut limbs = Vec::new();
+                    while n > 0 {
+                        limbs.push(n as u32);
+                        n >>= LIMB_BITS;
+                    }
+                    BigUint { limbs }
+                }
+            }
+        )*
+    };
+}
+biguint_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+fn add_biguint(a: &BigUint, b: &BigUint) -> BigUint {
+    BigUint {
+        limbs: add_limbs(&a.limbs, &b.limbs),
+    }
+}
+
+fn sub_biguint(a: &BigUint, b: &BigUint) -> BigUint {
+    assert!(a >= b, "BigUint subtraction underflow");
+    BigUint {
+        limbs: sub_limbs(&a.limbs, &b.limbs),
+    }
+}
+
+fn mul_biguint(a: &BigUint, b: &BigUint) -> BigUint {
+    BigUint {
+        limbs: mul_limbs(&a.limbs, &b.limbs),
+    }
+}
+
+fn div_biguint(a: &BigUint, b: &BigUint) -> BigUint {
+    a.divrem(b).0
+}
+
+fn rem_biguint(a: &BigUint, b: &BigUint) -> BigUint {
+    a.divrem(b).1
+}
+
+/// Implements a binary operator for all owned/borrowed combinations
+/// by forwarding to a function over references.
+macro_rules! forward_binop {
+    ($t:ty, $imp:ident, $method:ident, $func:ident) => {
+        impl<'a, 'b> $imp<&'b $t> for &'a $t {
+            type Output = $t;
+            fn $method(self, other: &'b $t) -> $t {
+                $func(self, other)
+            }
+        }
+
+        impl<'a> $imp<&'a $t> for $t {
+            type Output = $t;
+            fn $method(self, other: &'a $t) -> $t {
+                $func(&self, other)
+            }
+        }
+
+        impl<'a> $imp<$t> for &'a $t {
+            type Output = $t;
+            fn $method(self, other: $t) -> $t {
+                $func(self, &other)
+            }
+        }
+
+        impl $imp<$t> for $t {
+            type Output = $t;
+            fn $method(self, other: $t) -> $t {
+                $func(&self, &other)
+            }
+        }
+    };
+}
+
+forward_binop!(BigUint, Add, add, add_biguint);
+forward_binop!(BigUint, Sub, sub, sub_biguint);
+forward_binop!(BigUint, Mul, mul, mul_biguint);
+forward_binop!(BigUint, Div, div, div_biguint);
+forward_binop!(BigUint, Rem, rem, rem_biguint);
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+    fn shl(self, n: usize) -> BigUint {
+        BigUint {
+            limbs: shl_limbs(&self.limbs, n),
+        }
+    }
+}
+
+impl Shl<usize> for BigUint {
+    type Output = BigUint;
+    fn shl(self, n: usize) -> BigUint {
+        &self << n
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+    fn shr(self, n: usize) -> BigUint {
+        BigUint {
+            limbs: shr_limbs(&self.limbs, n),
+        }
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = BigUint;
+    fn shr(self, n: usize) -> BigUint {
+        &self >> n
+    }
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    pub fn one() -> Self {
+        BigInt::from(BigUint::one())
+    }
+
+    /// Builds a number from a sign and a magnitude.
+    pub fn from_biguint(negative: bool, magnitude: BigUint) -> Self {
+        BigInt {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// Returns the magnitude if the number is not negative.
+    pub fn to_biguint(&self) -> Option<BigUint> {
+        if self.negative {
+            None
+        } else {
+            Some(self.magnitude.clone())
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.magnitude.is_even()
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from(self.magnitude.clone())
+    }
+
+    /// Computes the truncated quotient and the remainder in one pass.
+    ///
+    /// ```text
+    /// self = q * d + r, |r| < |d|, r has the sign of self
+    /// ```
+    pub fn divrem(&self, d: &BigInt) -> (BigInt, BigInt) {
+        let (q, r) = self.magnitude.divrem(&d.magnitude);
+        (
+            BigInt::from_biguint(self.negative != d.negative, q),
+            BigInt::from_biguint(self.negative, r),
+        )
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|x| i64::try_from(x).ok())
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        let m = self.magnitude.to_u128()?;
+        if self.negative {
+            if m <= i128::MAX as u128 + 1 {
+                Some((m as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i128::try_from(m).ok()
+        }
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.negative {
+            None
+        } else {
+            self.magnitude.to_u128()
+        }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(rest) => Ok(BigInt::from_biguint(true, rest.parse()?)),
+            None => Ok(BigInt::from(s.parse::<BigUint>()?)),
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        BigInt {
+            negative: false,
+            magnitude,
+        }
+    }
+}
+
+macro_rules! bigint_from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                #[allow(unused_comparisons)]
+                fn from(n: $t) -> Self {
+                    let negative = n < 0;
+                    let magnitude = (n as i128).unsigned_abs();
+                    BigInt::from_biguint(negative, BigUint::from(magnitude))
+                }
+            }
+        )*
+    };
+}
+bigint_from_primitive!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<i128> for BigInt {
+    fn from(n: i128) -> Self {
+        BigInt::from_biguint(n < 0, BigUint::from(n.unsigned_abs()))
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(n: u128) -> Self {
+        BigInt::from(BigUint::from(n))
+    }
+}
+
+macro_rules! primitive_try_from_bigint {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<BigInt> for $t {
+                type Error = TryFromBigIntError;
+
+                fn try_from(n: BigInt) -> Result<Self, Self::Error> {
+                    n.to_i128()
+                        .and_then(|x| <$t>::try_from(x).ok())
+                        .ok_or(TryFromBigIntError)
+                }
+            }
+        )*
+    };
+}
+primitive_try_from_bigint!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl TryFrom<BigInt> for u128 {
+    type Error = TryFromBigIntError;
+
+    fn try_from(n: BigInt) -> Result<Self, Self::Error> {
+        n.to_u128().ok_or(TryFromBigIntError)
+    }
+}
+
+impl TryFrom<BigInt> for BigUint {
+    type Error = TryFromBigIntError;
+
+    fn try_from(n: BigInt) -> Result<Self, Self::Error> {
+        n.to_biguint().ok_or(TryFromBigIntError)
+    }
+}
+
+fn add_bigint(a: &BigInt, b: &BigInt) -> BigInt {
+    if a.negative == b.negative {
+        return BigInt::from_biguint(a.negative, &a.magnitude + &b.magnitude);
+    }
+    match a.magnitude.cmp(&b.magnitude) {
+        Ordering::Equal => BigInt::zero(),
+        Ordering::Greater => BigInt::from_biguint(a.negative, &a.magnitude - &b.magnitude),
+        Ordering::Less => BigInt::from_biguint(b.negative, &b.magnitude - &a.magnitude),
+    }
+}
+
+fn sub_bigint(a: &BigInt, b: &BigInt) -> BigInt {
+    add_bigint(a, &-b)
+}
+
+fn mul_bigint(a: &BigInt, b: &BigInt) -> BigInt {
+    BigInt::from_biguint(a.negative != b.negative, &a.magnitude * &b.magnitude)
+}
+
+fn div_bigint(a: &BigInt, b: &BigInt) -> BigInt {
+    a.divrem(b).0
+}
+
+fn rem_bigint(a: &BigInt, b: &BigInt) -> BigInt {
+    a.divrem(b).1
+}
+
+forward_binop!(BigInt, Add, add, add_bigint);
+forward_binop!(BigInt, Sub, sub, sub_bigint);
+forward_binop!(BigInt, Mul, mul, mul_bigint);
+forward_binop!(BigInt, Div, div, div_bigint);
+forward_binop!(BigInt, Rem, rem, rem_bigint);
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_biguint(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_biguint(!self.negative, self.magnitude)
+    }
+}
+
+impl Shl<usize> for &BigInt {
+    type Output = BigInt;
+    fn shl(self, n: usize) -> BigInt {
+        BigInt::from_biguint(self.negative, &self.magnitude << n)
+    }
+}
+
+impl Shl<usize> for BigInt {
+    type Output = BigInt;
+    fn shl(self, n: usize) -> BigInt {
+        &self << n
+    }
+}
+
+/// Arithmetic shift, rounds toward negative infinity like the primitive types.
+impl Shr<usize> for &BigInt {
+    type Output = BigInt;
+    fn shr(self, n: usize) -> BigInt {
+        if self.negative {
+            // -((|x| - 1) >> n) - 1
+            let m = &(&self.magnitude - &BigUint::one()) >> n;
+            BigInt::from_biguint(true, m + BigUint::one())
+        } else {
+            BigInt::from(&self.magnitude >> n)
+        }
+    }
+}
+
+impl Shr<usize> for BigInt {
+    type Output = BigInt;
+    fn shr(self, n: usize) -> BigInt {
+        &self >> n
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::{arith, gcd, Signed};
+
+/// Solves a system of congruences with the Chinese Remainder Theorem.
+///
+/// ```text
+/// x = a1 (mod m1)
+/// x = a2 (mod m2)
+/// ...
+/// Returns (x, M) with 0 <= x < M = lcm(m1, m2, ...),
+/// or None if the congruences are inconsistent.
+/// ```
+///
+/// ```text
+/// Algorithm, merge the congruences two at a time:
+/// (g, u, v) = xgcd(m1, m2), u * m1 + v * m2 = g
+/// solvable only if g | (a2 - a1)
+/// x = a1 + m1 * k
+/// m1 * k = a2 - a1 (mod m2)
+/// k = ((a2 - a1) / g) * u (mod m2 / g)
+/// The moduli need not be coprime, the merged modulus is lcm(m1, m2).
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::crt;
+///
+/// fn main() {
+///     let x = crt::crt(&[2, 3, 2], &[3, 5, 7]);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn crt<T: Signed>(residues: &[T], moduli: &[T]) -> Option<(T, T)> {
+    assert_eq!(residues.len(), moduli.len());
+    let mut x = T::zero();
+    let mut m = T::one();
+    for (a, n) in residues.iter().zip(moduli.iter()) {
+        let (x1, m1) = crt_pair(x, m, a.clone(), n.clone())?;
+        x = x1;
+        m = m1;
+    }
+    Some((x, m))
+}
+
+fn crt_pair<T: Signed>(a1: T, m1: T, a2: T, m2: T) -> Option<(T, T)> {
+    let (g, u, _) = gcd::xgcd(m1.clone(), m2.clone());
+    let d = arith::mod_sub(a2, a1.clone(), m2.clone());
+    if !(d.clone() % g.clone()).is_zero() {
+        return None;
+    }
+
+    let m2g = m2 / g.clone();
+    let lcm = m1.clone() * m2g.clone();
+    let k = arith::mod_mul(d / g, u, m2g);
+    let x = arith::mod_add(a1, arith::mod_mul(m1, k, lcm.clone()), lcm.clone());
+    Some((x, lcm))
+}
+
+/// Returns the mixed-radix digits of x with Garner's algorithm.
+///
+/// ```text
+/// x = v0 + v1 * m0 + v2 * m0 * m1 + ... + vk * m0 * ... * m(k-1)
+/// 0 <= vi < mi
+/// The moduli must be pairwise coprime.
+/// ```
+///
+/// ```text
+/// Algorithm:
+/// v0 = a0
+/// vi = (((ai - v0) * c(0,i) - v1) * c(1,i) - ... - v(i-1)) * c(i-1,i) (mod mi)
+/// c(j,i) = mj ^ -1 (mod mi), from xgcd(mj, mi)
+/// Every digit stays below its own modulus, no big intermediate product.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::crt;
+///
+/// fn main() {
+///     let v = crt::mixed_radix(&[2, 3, 2], &[3, 5, 7]);
+///     println!("{:?}", v);
+/// }
+/// ```
+pub fn mixed_radix<T: Signed>(residues: &[T], moduli: &[T]) -> Vec<T> {
+    assert_eq!(residues.len(), moduli.len());
+    let mut digits: Vec<T> = Vec::with_capacity(moduli.len());
+    for (i, (a, mi)) in residues.iter().zip(moduli.iter()).enumerate() {
+        let mut v = arith::reduce(a.clone(), mi.clone());
+        for j in 0..i {
+            let (g, c, _) = gcd::xgcd(moduli[j].clone(), mi.clone());
+            assert!(g.is_one(), "Garner moduli must be pairwise coprime");
+            v = arith::mod_mul(
+                arith::mod_sub(v, digits[j].clone(), mi.clone()),
+                c,
+                mi.clone(),
+            );
+        }
+        digits.push(v);
+    }
+    digits
+}
+
+/// Reconstructs x from its residues with Garner's algorithm.
+///
+/// ```text
+/// x = v0 + m0 * (v1 + m1 * (v2 + ...)), evaluated Horner style
+/// 0 <= x < m0 * m1 * ... * mk
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::crt;
+///
+/// fn main() {
+///     let x = crt::garner(&[2, 3, 2], &[3, 5, 7]);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn garner<T: Signed>(residues: &[T], moduli: &[T]) -> T {
+    let digits = mixed_radix(residues, moduli);
+    let mut x = T::zero();
+    for (v, m) in digits.into_iter().zip(moduli.iter()).rev() {
+        x = x * m.clone() + v;
+    }
+    x
+}
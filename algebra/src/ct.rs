@@ -0,0 +1,191 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::hint::black_box;
+
+/// Number of iterations of mod_mul and mod_exp, the width of a word.
+const WORD_BITS: u32 = 64;
+
+/// Returns all ones if choice is 1, zero if choice is 0.
+///
+/// ```text
+/// black_box hides that choice is a boolean, otherwise the optimizer may
+/// turn the masked select back into a branch.
+/// ```
+fn mask(choice: u64) -> u64 {
+    0u64.wrapping_sub(black_box(choice & 1))
+}
+
+/// Returns a if choice is 1, b if choice is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::ct;
+///
+/// fn main() {
+///     assert_eq!(ct::select(1, 14, 35), 14);
+///     assert_eq!(ct::select(0, 14, 35), 35);
+/// }
+/// ```
+pub fn select(choice: u64, a: u64, b: u64) -> u64 {
+    b ^ (mask(choice) & (a ^ b))
+}
+
+/// Swaps a and b if choice is 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::ct;
+///
+/// fn main() {
+///     let (mut a, mut b) = (14, 35);
+///     ct::swap(1, &mut a, &mut b);
+///     println!("{} {}", a, b);
+/// }
+/// ```
+pub fn swap(choice: u64, a: &mut u64, b: &mut u64) {
+    let t = mask(choice) & (*a ^ *b);
+    *a ^= t;
+    *b ^= t;
+}
+
+/// Returns 1 if a == b, 0 otherwise.
+pub fn eq(a: u64, b: u64) -> u64 {
+    let x = a ^ b;
+    // x | -x has the top bit set iff x != 0
+    1 ^ ((x | x.wrapping_neg()) >> 63)
+}
+
+/// Returns 1 if a < b, 0 otherwise.
+pub fn lt(a: u64, b: u64) -> u64 {
+    let (_, borrow) = a.overflowing_sub(b);
+    borrow as u64
+}
+
+/// Computes a + b (mod m) in constant time, a, b < m < 2^63.
+///
+/// ```text
+/// No branch, loop bound or memory index depends on the operands:
+/// both candidates are computed, then one is picked with select.
+/// m < 2^63, so a + b never overflows 64 bits.
+/// s = a + b, t = s - m
+/// s < m: s, otherwise t
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::ct;
+///
+/// fn main() {
+///     let x = ct::mod_add(14, 35, 37);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn mod_add(a: u64, b: u64, m: u64) -> u64 {
+    let s = a + b;
+    let (t, borrow) = s.overflowing_sub(m);
+    select(borrow as u64, s, t)
+}
+
+/// Computes a - b (mod m), a, b < m.
+///
+/// ```text
+/// t = a - b, wraps around if a < b, then m is added back under a mask.
+/// ```
+pub fn mod_sub(a: u64, b: u64, m: u64) -> u64 {
+    let (t, borrow) = a.overflowing_sub(b);
+    t.wrapping_add(mask(borrow as u64) & m)
+}
+
+/// Computes a (mod m) for any word a, m < 2^63.
+///
+/// ```text
+/// Shifts the 64 bits of a in from the top, leading zeros included:
+/// res = 2 * res + bit (mod m)
+/// No division, the hardware % is variable time on many CPUs.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::ct;
+///
+/// fn main() {
+///     let x = ct::reduce(u64::MAX, 37);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn reduce(a: u64, m: u64) -> u64 {
+    let mut res = 0;
+    for i in (0..WORD_BITS).rev() {
+        res = mod_add(res, res, m);
+        let sum = mod_add(res, 1, m);
+        res = select((a >> i) & 1, sum, res);
+    }
+    res
+}
+
+/// Computes a * b (mod m), a, b < m.
+///
+/// ```text
+/// Double-and-add over all 64 bits of b, leading zeros included:
+/// res = 2 * res (mod m)
+/// res = res + a (mod m), always computed, kept if the bit is 1
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::ct;
+///
+/// fn main() {
+///     let x = ct::mod_mul(14, 35, 37);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    let mut res = 0;
+    for i in (0..WORD_BITS).rev() {
+        res = mod_add(res, res, m);
+        let sum = mod_add(res, a, m);
+        res = select((b >> i) & 1, sum, res);
+    }
+    res
+}
+
+/// Computes base^exponent (mod m), base < m, with a Montgomery ladder.
+///
+/// ```text
+/// r0 = 1, r1 = base, invariant r1 = r0 * base
+/// for each of the 64 bits of exponent:
+///     swap(bit, r0, r1)
+///     r1 = r0 * r1, r0 = r0^2
+///     swap(bit, r0, r1)
+/// Every step does one multiplication and one squaring.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::ct;
+///
+/// fn main() {
+///     let x = ct::mod_exp(15, 33, 37);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn mod_exp(base: u64, exponent: u64, m: u64) -> u64 {
+    let mut r0 = 1 % m;
+    let mut r1 = base;
+    for i in (0..WORD_BITS).rev() {
+        let bit = (exponent >> i) & 1;
+        swap(bit, &mut r0, &mut r1);
+        r1 = mod_mul(r0, r1, m);
+        r0 = mod_mul(r0, r0, m);
+        swap(bit, &mut r0, &mut r1);
+    }
+    r0
+}
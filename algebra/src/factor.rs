@@ -0,0 +1,293 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::barrett::BarrettReducer;
+use crate::bigint::BigUint;
+use crate::rng::{Rng, SplitMix64};
+use crate::{gcd, primality, Integer};
+
+/// Trial division limit used by factorize.
+const TRIAL_BOUND: u32 = 1000;
+
+/// Smoothness bound used by factorize for Pollard's p - 1.
+const P_MINUS_1_BOUND: u64 = 10000;
+
+/// Divides out all prime factors of n not greater than bound.
+///
+/// ```text
+/// Returns the prime powers found and the cofactor left,
+/// the cofactor has no prime factor <= bound.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::factor;
+///
+/// fn main() {
+///     let (factors, rest) = factor::trial_division(360 * 1009, 100);
+///     println!("{:?} {}", factors, rest);
+/// }
+/// ```
+pub fn trial_division<T: Integer>(n: T, bound: u32) -> (Vec<(T, u32)>, T) {
+    let (factors, rest) = trial_division_big(lift(&n), bound);
+    let factors = factors.iter().map(|(p, e)| (lower(p), *e)).collect();
+    (factors, lower(&rest))
+}
+
+/// Finds a non-trivial factor of n with Pollard's rho, Brent's variant.
+///
+/// ```text
+/// f(x) = x^2 + c (mod n) walks into a cycle mod every prime p | n,
+/// after about sqrt(p) steps, then gcd(|x - y|, n) reveals p.
+/// Brent: x is kept at the powers of two steps r, y walks on,
+/// and the |x - y| are multiplied together, one gcd per m steps.
+/// Returns None if n is a prime, or if no c in 1..=20 works.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::factor;
+///
+/// fn main() {
+///     let d = factor::pollard_rho(8051);
+///     println!("{:?}", d);
+/// }
+/// ```
+pub fn pollard_rho<T: Integer>(n: T) -> Option<T> {
+    pollard_rho_big(&lift(&n)).map(|d| lower(&d))
+}
+
+/// Finds a non-trivial factor of n with Pollard's p - 1.
+///
+/// ```text
+/// If p | n and p - 1 is bound-smooth, then (p - 1) | E,
+/// E = product of q^e <= bound over all primes q <= bound
+/// a^E = 1 (mod p) by Fermat, so p | gcd(a^E - 1, n)
+/// Returns None if no p - 1 is smooth enough, or all of them are.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::factor;
+///
+/// fn main() {
+///     let d = factor::pollard_p_minus_1(1403, 10);
+///     println!("{:?}", d);
+/// }
+/// ```
+pub fn pollard_p_minus_1<T: Integer>(n: T, bound: u64) -> Option<T> {
+    pollard_p_minus_1_big(&lift(&n), bound).map(|d| lower(&d))
+}
+
+/// Factorizes n into prime powers, sorted by prime.
+///
+/// ```text
+/// 1. trial division by the primes up to 1000
+/// 2. a cofactor is split with Pollard's p - 1, then Pollard's rho,
+///    then Pollard's rho from random starts until it splits,
+///    until every part passes primality::is_prime
+/// n = p1^e1 * p2^e2 * ... * pk^ek
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::factor;
+///
+/// fn main() {
+///     let factors = factor::factorize(360);
+///     println!("{:?}", factors);
+/// }
+/// ```
+pub fn factorize<T: Integer>(n: T) -> Vec<(T, u32)> {
+    let n = lift(&n);
+    assert!(!n.is_zero(), "zero has no factorization");
+
+    let (mut factors, rest) = trial_division_big(n, TRIAL_BOUND);
+    let mut rng = SplitMix64::new(0);
+    let mut stack = vec![rest];
+    while let Some(m) = stack.pop() {
+        if m.is_one() {
+            continue;
+        }
+        if primality::is_prime(m.clone()) {
+            factors.push((m, 1));
+            continue;
+        }
+        let d = pollard_p_minus_1_big(&m, P_MINUS_1_BOUND)
+            .or_else(|| pollard_rho_big(&m))
+            .unwrap_or_else(|| pollard_rho_random(&m, &mut rng));
+        stack.push(&m / &d);
+        stack.push(d);
+    }
+
+    factors.sort();
+    let mut merged: Vec<(BigUint, u32)> = Vec::new();
+    for (p, e) in factors {
+        match merged.last_mut() {
+            Some((q, f)) if *q == p => *f += e,
+            _ => merged.push((p, e)),
+        }
+    }
+    merged.iter().map(|(p, e)| (lower(p), *e)).collect()
+}
+
+fn lift<T: Integer>(n: &T) -> BigUint {
+    n.to_bigint()
+        .to_biguint()
+        .expect("factorization of a negative number")
+}
+
+fn lower<T: Integer>(n: &BigUint) -> T {
+    T::from_bigint(&n.clone().into()).unwrap()
+}
+
+fn trial_division_big(mut n: BigUint, bound: u32) -> (Vec<(BigUint, u32)>, BigUint) {
+    let mut factors = Vec::new();
+    let mut d = 2u32;
+    while d <= bound && BigUint::from(d as u64 * d as u64) <= n {
+        let p = BigUint::from(d);
+        let mut e = 0;
+        loop {
+            let (q, r) = n.divrem(&p);
+            if !r.is_zero() {
+                break;
+            }
+            n = q;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((p, e));
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+    // What is left below d * d is a prime.
+    if !n.is_one() && n <= BigUint::from(bound) {
+        factors.push((n, 1));
+        n = BigUint::one();
+    }
+    (factors, n)
+}
+
+/// |a - b|
+fn abs_diff(a: &BigUint, b: &BigUint) -> BigUint {
+    if a >= b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn pollard_rho_big(n: &BigUint) -> Option<BigUint> {
+    if *n < BigUint::from(4u32) || primality::is_prime(n.clone()) {
+        return None;
+    }
+    if n.is_even() {
+        return Some(BigUint::from(2u32));
+    }
+    let two = BigUint::from(2u32);
+    (1..=20u32).find_map(|c| brent(n, &two, &BigUint::from(c)))
+}
+
+/// Retries Brent from random starts and constants until the odd composite n splits,
+/// every attempt fails only with a small probability.
+fn pollard_rho_random<R: Rng>(n: &BigUint, rng: &mut R) -> BigUint {
+    // c = 0 and c = -2 give degenerate walks.
+    let high = n - &BigUint::from(2u32);
+    loop {
+        let y = rng.gen_below(n);
+        let c = rng.gen_range(&BigUint::one(), &high);
+        if let Some(d) = brent(n, &y, &c) {
+            return d;
+        }
+    }
+}
+
+fn brent(n: &BigUint, y0: &BigUint, c: &BigUint) -> Option<BigUint> {
+    let f = |x: &BigUint| (x * x + c) % n;
+    let m = 128;
+    let (mut y, mut x, mut ys) = (y0.clone(), BigUint::zero(), BigUint::zero());
+    let (mut g, mut q, mut r) = (BigUint::one(), BigUint::one(), 1usize);
+
+    while g.is_one() {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g.is_one() {
+            ys = y.clone();
+            for _ in 0..m.min(r - k) {
+                y = f(&y);
+                q = (&q * &abs_diff(&x, &y)) % n;
+            }
+            g = gcd::gcd(q.clone(), n.clone());
+            k += m;
+        }
+        r *= 2;
+    }
+
+    // The product hit zero mod n, redo the last batch one step at a time.
+    if g == *n {
+        loop {
+            ys = f(&ys);
+            g = gcd::gcd(abs_diff(&x, &ys), n.clone());
+            if !g.is_one() {
+                break;
+            }
+        }
+    }
+    if g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+fn pollard_p_minus_1_big(n: &BigUint, bound: u64) -> Option<BigUint> {
+    if *n < BigUint::from(4u32) {
+        return None;
+    }
+    if n.is_even() {
+        return Some(BigUint::from(2u32));
+    }
+
+    let br = BarrettReducer::new(n);
+    let mut a = BigUint::from(2u32);
+    for q in primes_up_to(bound) {
+        let mut qe = q;
+        while qe <= bound / q {
+            qe *= q;
+        }
+        a = br.exp(&a, &BigUint::from(qe));
+    }
+
+    // gcd(2, n) = 1, so a >= 1.
+    let g = gcd::gcd(a - BigUint::one(), n.clone());
+    if g.is_one() || g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+/// Sieve of Eratosthenes.
+pub(crate) fn primes_up_to(bound: u64) -> Vec<u64> {
+    let bound = bound as usize;
+    let mut composite = vec![false; bound + 1];
+    let mut primes = Vec::new();
+    for i in 2..=bound {
+        if !composite[i] {
+            primes.push(i as u64);
+            let mut j = i * i;
+            while j <= bound {
+                composite[j] = true;
+                j += i;
+            }
+        }
+    }
+    primes
+}
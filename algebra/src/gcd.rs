@@ -3,6 +3,8 @@
 
 use std::mem;
 
+use crate::{Integer, Signed};
+
 /// Implements Euclidean algorithm with non-recursive
 ///
 /// ```text
@@ -24,10 +26,10 @@ use std::mem;
 /// }
 /// ```
 ///
-pub fn gcd(mut a: i8, mut b: i8) -> i8 {
-    while b != 0 {
-        a %= b;
-        std::mem::swap(&mut a, &mut b)
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while !b.is_zero() {
+        a = a % b.clone();
+        mem::swap(&mut a, &mut b)
     }
     a
 }
@@ -39,6 +41,8 @@ pub fn gcd(mut a: i8, mut b: i8) -> i8 {
 /// a * x + b * y == gcd(a, b).
 /// ```
 ///
+/// The coefficients can be negative, so T must be a signed type.
+///
 /// # Examples
 ///
 /// ```rust
@@ -50,16 +54,16 @@ pub fn gcd(mut a: i8, mut b: i8) -> i8 {
 /// }
 /// ```
 ///
-pub fn xgcd(a: i8, b: i8) -> (i8, i8, i8) {
-    let (mut sj, mut sj_last) = (0, 1);
-    let (mut tj, mut tj_last) = (1, 0);
+pub fn xgcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut sj, mut sj_last) = (T::zero(), T::one());
+    let (mut tj, mut tj_last) = (T::one(), T::zero());
     let (mut rj, mut rj_last) = (b, a);
 
-    while rj != 0 {
-        let quotient = rj_last / rj;
-        rj_last -= quotient * rj;
-        sj_last -= quotient * sj;
-        tj_last -= quotient * tj;
+    while !rj.is_zero() {
+        let quotient = rj_last.clone() / rj.clone();
+        rj_last = rj_last - quotient.clone() * rj.clone();
+        sj_last = sj_last - quotient.clone() * sj.clone();
+        tj_last = tj_last - quotient * tj.clone();
         mem::swap(&mut rj, &mut rj_last);
         mem::swap(&mut sj, &mut sj_last);
         mem::swap(&mut tj, &mut tj_last);
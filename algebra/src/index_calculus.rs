@@ -0,0 +1,266 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::rng::Rng;
+use crate::{arith, crt, factor};
+
+/// Extra relations collected beyond the size of the factor base.
+const EXTRA_RELATIONS: usize = 10;
+
+/// Rounds of relation collection before giving up.
+const ROUNDS: usize = 8;
+
+/// Attempts with a fresh random s before the descent gives up.
+const ATTEMPTS: usize = 16;
+
+/// Returns the factor base, all primes up to bound.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::index_calculus;
+///
+/// fn main() {
+///     println!("{:?}", index_calculus::factor_base(30));
+/// }
+/// ```
+pub fn factor_base(bound: u64) -> Vec<u64> {
+    factor::primes_up_to(bound)
+}
+
+/// Returns the exponents of n over the factor base if n is smooth, otherwise None.
+///
+/// ```text
+/// n = base[0]^e0 * base[1]^e1 * ... * base[k]^ek
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::index_calculus;
+///
+/// fn main() {
+///     let e = index_calculus::smooth_exponents(360, &[2, 3, 5, 7]);
+///     println!("{:?}", e);
+/// }
+/// ```
+pub fn smooth_exponents(mut n: u64, base: &[u64]) -> Option<Vec<u64>> {
+    if n == 0 {
+        return None;
+    }
+    let mut exponents = vec![0; base.len()];
+    for (i, &q) in base.iter().enumerate() {
+        while n % q == 0 {
+            n /= q;
+            exponents[i] += 1;
+        }
+    }
+    if n == 1 {
+        Some(exponents)
+    } else {
+        None
+    }
+}
+
+/// Computes the logarithms of the factor base to the base g (mod p - 1).
+///
+/// ```text
+/// g must be a generator of Z_p^*.
+/// Returns None if the linear system stays singular.
+/// ```
+///
+/// ```text
+/// Relation collection:
+/// k random, g^k mod p smooth over the base:
+/// g^k = q0^e0 * ... * qr^er (mod p)
+/// k = e0 * log(q0) + ... + er * log(qr) (mod p - 1)
+///
+/// Linear algebra:
+/// p - 1 is not a prime, so the system is solved modulo every
+/// prime power l^f of p - 1 by Gaussian elimination with pivots prime to l,
+/// and the solutions are combined with CRT.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::index_calculus;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let base = index_calculus::factor_base(30);
+///     let mut rng = SplitMix64::new(1);
+///     let logs = index_calculus::factor_base_logs(2, 1019, &base, &mut rng);
+///     println!("{:?}", logs);
+/// }
+/// ```
+pub fn factor_base_logs<R: Rng>(g: u64, p: u64, base: &[u64], rng: &mut R) -> Option<Vec<u64>> {
+    let n = p - 1;
+    let moduli = factor::factorize(n);
+    let mut rows: Vec<Vec<u64>> = Vec::new();
+    let mut rhs: Vec<u64> = Vec::new();
+
+    for round in 1..=ROUNDS {
+        // Walk k, k + 1, ... from a random start, one product per step.
+        let mut k = rng.next_u64() % n;
+        let mut gk = arith::mod_exp(g, k, p);
+        while rows.len() < base.len() + round * EXTRA_RELATIONS {
+            if let Some(e) = smooth_exponents(gk, base) {
+                rows.push(e);
+                rhs.push(k);
+            }
+            k = (k + 1) % n;
+            gk = arith::mod_mul(gk, g, p);
+        }
+
+        let mut solutions = Vec::new();
+        for &(l, f) in moduli.iter() {
+            match solve_mod(&rows, &rhs, base.len(), l, l.pow(f)) {
+                Some(x) => solutions.push((x, l.pow(f))),
+                None => break,
+            }
+        }
+        if solutions.len() < moduli.len() {
+            continue;
+        }
+
+        let logs = (0..base.len())
+            .map(|i| {
+                let residues: Vec<i128> = solutions.iter().map(|(x, _)| x[i] as i128).collect();
+                let ms: Vec<i128> = solutions.iter().map(|(_, m)| *m as i128).collect();
+                crt::crt(&residues, &ms).unwrap().0 as u64
+            })
+            .collect();
+        return Some(logs);
+    }
+    None
+}
+
+/// Solves h = g^x (mod p) with index calculus, factor base bound chosen from p.
+///
+/// ```text
+/// g must be a generator of Z_p^*.
+/// Returns x in [0, p - 1), or None.
+/// ```
+///
+/// ```text
+/// The running time is sub-exponential in the size of p,
+/// L(p) = exp(sqrt(ln p * ln ln p)), while the best generic DLP
+/// algorithms (bsgs, rho) on an elliptic curve group need sqrt(n) steps,
+/// so a 256-bit curve matches a 3072-bit prime field.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::index_calculus;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(1);
+///     let x = index_calculus::index_calculus(2, 10, 1019, &mut rng);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn index_calculus<R: Rng>(g: u64, h: u64, p: u64, rng: &mut R) -> Option<u64> {
+    let lp = (p as f64).ln();
+    let bound = (0.6 * (lp * lp.ln()).sqrt()).exp() as u64;
+    index_calculus_with_bound(g, h, p, bound.max(30), rng)
+}
+
+/// Solves h = g^x (mod p) with index calculus over the primes up to bound.
+///
+/// ```text
+/// 1. factor base logs, see factor_base_logs
+/// 2. individual log descent:
+///    s random, h * g^s mod p smooth over the base:
+///    h * g^s = q0^e0 * ... * qr^er (mod p)
+///    x = e0 * log(q0) + ... + er * log(qr) - s (mod p - 1)
+///    a candidate x is checked against h, a new s is drawn if it fails
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::index_calculus;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(1);
+///     let x = index_calculus::index_calculus_with_bound(2, 10, 1019, 20, &mut rng);
+///     println!("{:?}", x);
+/// }
+/// ```
+pub fn index_calculus_with_bound<R: Rng>(
+    g: u64,
+    h: u64,
+    p: u64,
+    bound: u64,
+    rng: &mut R,
+) -> Option<u64> {
+    if h % p == 0 {
+        return None;
+    }
+    let n = p - 1;
+    let base: Vec<u64> = factor_base(bound).into_iter().filter(|&q| q < p).collect();
+    let logs = factor_base_logs(g, p, &base, rng)?;
+
+    for _ in 0..ATTEMPTS {
+        let mut s = rng.next_u64() % n;
+        let mut y = arith::mod_mul(h, arith::mod_exp(g, s, p), p);
+        for _ in 0..n {
+            if let Some(e) = smooth_exponents(y, &base) {
+                let mut x = 0;
+                for (ei, li) in e.iter().zip(logs.iter()) {
+                    x = arith::mod_add(x, arith::mod_mul(*ei, *li, n), n);
+                }
+                let x = arith::mod_sub(x, s, n);
+                if arith::mod_exp(g, x, p) == h % p {
+                    return Some(x);
+                }
+                break;
+            }
+            s = (s + 1) % n;
+            y = arith::mod_mul(y, g, p);
+        }
+    }
+    None
+}
+
+/// Solves rows * x = rhs (mod m), m = l^f, by Gauss-Jordan elimination.
+///
+/// ```text
+/// A pivot must be a unit mod m, that is not divisible by l.
+/// Returns None if some column has no such pivot.
+/// ```
+fn solve_mod(rows: &[Vec<u64>], rhs: &[u64], cols: usize, l: u64, m: u64) -> Option<Vec<u64>> {
+    let mut a: Vec<Vec<u64>> = rows
+        .iter()
+        .zip(rhs.iter())
+        .map(|(row, r)| {
+            let mut v: Vec<u64> = row.iter().map(|e| e % m).collect();
+            v.push(r % m);
+            v
+        })
+        .collect();
+
+    for c in 0..cols {
+        let pivot = (c..a.len()).find(|&r| a[r][c] % l != 0)?;
+        a.swap(c, pivot);
+        let inv = arith::mod_inv(a[c][c], m);
+        for v in a[c].iter_mut() {
+            *v = arith::mod_mul(*v, inv, m);
+        }
+        let pivot_row = a[c].clone();
+        for (r, row) in a.iter_mut().enumerate() {
+            if r == c || row[c] == 0 {
+                continue;
+            }
+            let factor = row[c];
+            for (v, pv) in row.iter_mut().zip(pivot_row.iter()).skip(c) {
+                *v = arith::mod_sub(*v, arith::mod_mul(factor, *pv, m), m);
+            }
+        }
+    }
+    Some((0..cols).map(|c| a[c][cols]).collect())
+}
@@ -0,0 +1,251 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Neg, Rem, Shl, Shr, Sub};
+
+use crate::arith;
+use crate::bigint::{BigInt, BigUint};
+
+/// Integer types the modular arithmetic works on.
+///
+/// ```text
+/// Implemented for u8..u128, i8..i128, BigUint and BigInt.
+/// Division truncates toward zero and the remainder has the sign of
+/// the dividend, as with the primitive types.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::arith;
+///
+/// fn main() {
+///     let x = arith::mod_add(200u8, 100u8, 251u8);
+///     let y = arith::mod_add(200u64, 100u64, 251u64);
+///     assert_eq!(x as u64, y);
+/// }
+/// ```
+pub trait Integer:
+    Clone
+    + Debug
+    + Display
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Shl<usize, Output = Self>
+    + Shr<usize, Output = Self>
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn is_negative(&self) -> bool;
+
+    fn is_even(&self) -> bool;
+
+    /// Returns the number of significant bits of a non-negative value.
+    fn bits(&self) -> usize;
+
+    /// Returns the i-th bit of a non-negative value.
+    fn bit(&self, i: usize) -> bool;
+
+    fn from_i64(n: i64) -> Option<Self>;
+
+    fn to_i64(&self) -> Option<i64>;
+
+    fn from_bigint(n: &BigInt) -> Option<Self>;
+
+    fn to_bigint(&self) -> BigInt;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+
+    fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    fn two() -> Self {
+        Self::one() + Self::one()
+    }
+
+    /// Computes self * b (mod m), self and b in [0, m).
+    ///
+    /// ```text
+    /// Double-and-add by default, a * b would overflow a fixed width.
+    /// BigUint and BigInt never overflow, they multiply and take the remainder.
+    /// ```
+    fn mul_mod(&self, b: &Self, m: &Self) -> Self {
+        let mut res = Self::zero();
+        for i in (0..b.bits()).rev() {
+            res = arith::mod_add(res.clone(), res, m.clone());
+            if b.bit(i) {
+                res = arith::mod_add(res, self.clone(), m.clone());
+            }
+        }
+        res
+    }
+}
+
+/// Integer types that can hold negative values, needed by the Bezout
+/// coefficients of gcd::xgcd.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! integer_impl {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                #[allow(unused_comparisons)]
+                fn is_negative(&self) -> bool {
+                    *self < 0
+                }
+
+                fn is_even(&self) -> bool {
+                    *self & 1 == 0
+                }
+
+                fn bits(&self) -> usize {
+                    (<$t>::BITS - self.leading_zeros()) as usize
+                }
+
+                fn bit(&self, i: usize) -> bool {
+                    i < <$t>::BITS as usize && (*self >> i) & 1 == 1
+                }
+
+                fn from_i64(n: i64) -> Option<Self> {
+                    <$t>::try_from(n).ok()
+                }
+
+                fn to_i64(&self) -> Option<i64> {
+                    i64::try_from(*self).ok()
+                }
+
+                fn from_bigint(n: &BigInt) -> Option<Self> {
+                    match n.to_i128() {
+                        Some(v) => <$t>::try_from(v).ok(),
+                        None => n.to_u128().and_then(|v| <$t>::try_from(v).ok()),
+                    }
+                }
+
+                fn to_bigint(&self) -> BigInt {
+                    BigInt::from(*self)
+                }
+            }
+        )*
+    };
+}
+integer_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for BigInt {}
+
+impl Integer for BigUint {
+    fn zero() -> Self {
+        BigUint::zero()
+    }
+
+    fn one() -> Self {
+        BigUint::one()
+    }
+
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    fn is_even(&self) -> bool {
+        BigUint::is_even(self)
+    }
+
+    fn bits(&self) -> usize {
+        BigUint::bits(self)
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        BigUint::bit(self, i)
+    }
+
+    fn from_i64(n: i64) -> Option<Self> {
+        u64::try_from(n).ok().map(BigUint::from)
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u64().and_then(|v| i64::try_from(v).ok())
+    }
+
+    fn from_bigint(n: &BigInt) -> Option<Self> {
+        n.to_biguint()
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        BigInt::from(self.clone())
+    }
+    fn mul_mod(&self, b: &Self, m: &Self) -> Self {
+        &(self * b) % m
+    }
+}
+
+impl Integer for BigInt {
+    fn zero() -> Self {
+        BigInt::zero()
+    }
+
+    fn one() -> Self {
+        BigInt::one()
+    }
+
+    fn is_negative(&self) -> bool {
+        BigInt::is_negative(self)
+    }
+
+    fn is_even(&self) -> bool {
+        BigInt::is_even(self)
+    }
+
+    fn bits(&self) -> usize {
+        self.magnitude().bits()
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        self.magnitude().bit(i)
+    }
+
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        BigInt::to_i64(self)
+    }
+
+    fn from_bigint(n: &BigInt) -> Option<Self> {
+        Some(n.clone())
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        self.clone()
+    }
+    fn mul_mod(&self, b: &Self, m: &Self) -> Self {
+        &(self * b) % m
+    }
+}
@@ -2,4 +2,18 @@
 // Code is licensed with BSD
 
 pub mod arith;
+pub mod barrett;
+pub mod bigint;
+pub mod crt;
+pub mod ct;
+pub mod factor;
 pub mod gcd;
+pub mod index_calculus;
+pub mod integer;
+pub mod montgomery;
+pub mod primality;
+pub mod prime;
+pub mod rng;
+pub mod symbol;
+
+pub use integer::{Integer, Signed};
@@ -0,0 +1,193 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::bigint::BigUint;
+
+/// Montgomery multiplication context for a fixed odd modulus.
+///
+/// ```text
+/// b = 2^32 (limb base), n = limbs of m, R = b^n > m, gcd(R, m) = 1
+/// Montgomery form:    x' = x * R (mod m)
+/// Montgomery product: mont_mul(x', y') = x' * y' * R^-1 = (x * y)' (mod m)
+/// The division by R is a shift, so no division by m is needed
+/// once the values are in Montgomery form.
+/// fields::field::Field holds one per Reduction::Montgomery field.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::bigint::BigUint;
+/// use algebra::montgomery::MontgomeryContext;
+///
+/// fn main() {
+///     let ctx = MontgomeryContext::new(&BigUint::from(37u32));
+///     let x = ctx.to_mont(&BigUint::from(14u32));
+///     let y = ctx.to_mont(&BigUint::from(35u32));
+///     let z = ctx.from_mont(&ctx.mont_mul(&x, &y));
+///     println!("{}", z);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryContext {
+    m: BigUint,
+    n: usize,
+    r: BigUint,
+    r2: BigUint,
+    m_prime: u32,
+}
+
+/// acc += src * k, acc must have room for the carry.
+fn add_mul_limb(acc: &mut [u32], src: &[u32], k: u64) {
+    let mut carry = 0u64;
+    for (j, &s) in src.iter().enumerate() {
+        let t = acc[j] as u64 + k * s as u64 + carry;
+        acc[j] = t as u32;
+        carry = t >> 32;
+    }
+    let mut j = src.len();
+    while carry > 0 {
+        let t = acc[j] as u64 + carry;
+        acc[j] = t as u32;
+        carry = t >> 32;
+        j += 1;
+    }
+}
+
+impl MontgomeryContext {
+    /// Precomputes R mod m, R^2 mod m and m' = -m^-1 (mod b).
+    ///
+    /// ```text
+    /// m' is found with Newton's iteration on the lowest limb:
+    /// inv = inv * (2 - m0 * inv) (mod 2^32)
+    /// every step doubles the number of correct low bits.
+    /// ```
+    pub fn new(m: &BigUint) -> Self {
+        assert!(m.is_odd() && !m.is_one(), "Montgomery modulus must be odd");
+        let n = m.limbs().len();
+        let r = (BigUint::one() << (32 * n)) % m;
+        let r2 = (&r * &r) % m;
+
+        let m0 = m.limbs()[0];
+        let mut inv = 1u32;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(m0.wrapping_mul(inv)));
+        }
+
+        MontgomeryContext {
+            m: m.clone(),
+            n,
+            r,
+            r2,
+            m_prime: inv.wrapping_neg(),
+        }
+    }
+
+    /// Returns the modulus m.
+    pub fn modulus(&self) -> &BigUint {
+        &self.m
+    }
+
+    /// Returns R = b^n.
+    pub fn r(&self) -> BigUint {
+        BigUint::one() << (32 * self.n)
+    }
+
+    /// Returns R^2 mod m.
+    pub fn r2(&self) -> &BigUint {
+        &self.r2
+    }
+
+    /// Returns -m^-1 mod b.
+    pub fn m_prime(&self) -> u32 {
+        self.m_prime
+    }
+
+    /// Returns 1 in Montgomery form, that is R mod m.
+    pub fn one(&self) -> BigUint {
+        self.r.clone()
+    }
+
+    /// Converts x into Montgomery form.
+    ///
+    /// ```text
+    /// x' = mont_mul(x, R^2) = x * R (mod m)
+    /// ```
+    pub fn to_mont(&self, x: &BigUint) -> BigUint {
+        self.mont_mul(&(x % &self.m), &self.r2)
+    }
+
+    /// Converts x' out of Montgomery form.
+    ///
+    /// ```text
+    /// x = mont_mul(x', 1) = x' * R^-1 (mod m)
+    /// ```
+    pub fn from_mont(&self, x: &BigUint) -> BigUint {
+        self.mont_mul(x, &BigUint::one())
+    }
+
+    /// Computes the Montgomery product of x and y, both less than m.
+    ///
+    /// ```text
+    /// x * y * R^-1 (mod m)
+    /// ```
+    ///
+    /// ```text
+    /// Algorithm (HAC 14.36):
+    /// A = 0
+    /// for i in 0..n:
+    ///     u = (a0 + x[i] * y0) * m' (mod b)
+    ///     A = (A + x[i] * y + u * m) / b
+    /// if A >= m: A = A - m
+    /// ```
+    pub fn mont_mul(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        let (xl, yl, ml) = (x.limbs(), y.limbs(), self.m.limbs());
+        let mut a = vec![0u32; self.n + 2];
+        for i in 0..self.n {
+            let xi = *xl.get(i).unwrap_or(&0) as u64;
+            add_mul_limb(&mut a, yl, xi);
+            let u = a[0].wrapping_mul(self.m_prime) as u64;
+            add_mul_limb(&mut a, ml, u);
+            // a[0] is zero now, divide by b.
+            a.remove(0);
+            a.push(0);
+        }
+
+        let res = BigUint::from_limbs(&a);
+        if res >= self.m {
+            res - &self.m
+        } else {
+            res
+        }
+    }
+
+    /// Computes exponention in Montgomery form.
+    ///
+    /// ```text
+    /// base' ^ exponent = (base ^ exponent)' (mod m)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use algebra::bigint::BigUint;
+    /// use algebra::montgomery::MontgomeryContext;
+    ///
+    /// fn main() {
+    ///     let ctx = MontgomeryContext::new(&BigUint::from(37u32));
+    ///     let x = ctx.to_mont(&BigUint::from(18u32));
+    ///     let y = ctx.mont_exp(&x, &BigUint::from(11u32));
+    ///     println!("{}", ctx.from_mont(&y));
+    /// }
+    /// ```
+    pub fn mont_exp(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let mut res = self.one();
+        for i in (0..exponent.bits()).rev() {
+            res = self.mont_mul(&res, &res);
+            if exponent.bit(i) {
+                res = self.mont_mul(&res, base);
+            }
+        }
+        res
+    }
+}
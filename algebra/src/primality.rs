@@ -0,0 +1,392 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::bigint::BigUint;
+use crate::montgomery::MontgomeryContext;
+use crate::symbol::{self, Symbol};
+use crate::{arith, Integer};
+
+/// Primes below 100, the first Miller-Rabin bases and trial divisors.
+pub(crate) const SMALL_PRIMES: [u32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// (bound, k): the first k prime bases decide every n < bound.
+const DETERMINISTIC_BOUNDS: [(u128, usize); 10] = [
+    (2_047, 1),
+    (1_373_653, 2),
+    (25_326_001, 3),
+    (3_215_031_751, 4),
+    (2_152_302_898_747, 5),
+    (3_474_749_660_383, 6),
+    (341_550_071_728_321, 7),
+    (3_825_123_056_546_413_051, 9),
+    (318_665_857_834_031_151_167_461, 12),
+    (3_317_044_064_679_887_385_961_981, 13),
+];
+
+/// Finds the smallest prime factor of n not greater than bound.
+///
+/// ```text
+/// Divides by 2, 3, 5, 7, 9, ... while d <= bound and d * d <= n.
+/// If no divisor is found below sqrt(n), n itself is prime.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::primality;
+///
+/// fn main() {
+///     let d = primality::small_factor(91, 10);
+///     println!("{:?}", d);
+/// }
+/// ```
+pub fn small_factor<T: Integer>(n: T, bound: T) -> Option<T> {
+    let two = T::two();
+    if n < two {
+        return None;
+    }
+
+    let mut d = two.clone();
+    while d <= bound && d <= n.clone() / d.clone() {
+        if (n.clone() % d.clone()).is_zero() {
+            return Some(d);
+        }
+        d = if d == two {
+            d + T::one()
+        } else {
+            d + two.clone()
+        };
+    }
+    if n <= bound {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+/// Tests primality by trial division up to sqrt(n), only for small n.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::primality;
+///
+/// fn main() {
+///     println!("{}", primality::is_prime_trial(37));
+/// }
+/// ```
+pub fn is_prime_trial<T: Integer>(n: T) -> bool {
+    small_factor(n.clone(), n.clone()) == Some(n)
+}
+
+/// Miller-Rabin test of n against the given bases.
+///
+/// ```text
+/// n - 1 = d * 2^s, d odd
+/// n is a strong probable prime to base a if
+/// a ^ d = 1 (mod n), or
+/// a ^ (d * 2^r) = -1 (mod n) for some 0 <= r < s
+/// A composite n passes a random base with probability at most 1/4.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::primality;
+///
+/// fn main() {
+///     println!("{}", primality::miller_rabin(2047, &[2]));
+/// }
+/// ```
+pub fn miller_rabin<T: Integer>(n: T, bases: &[T]) -> bool {
+    let m = match lift(&n) {
+        Some(m) => m,
+        None => return false,
+    };
+    if let Some(prime) = small_cases(&m) {
+        return prime;
+    }
+
+    let ctx = MontgomeryContext::new(&m);
+    bases.iter().all(|a| {
+        let a = lift(&arith::reduce(a.clone(), n.clone())).unwrap();
+        strong_probable_prime(&ctx, &a)
+    })
+}
+
+/// Miller-Rabin test with the first rounds primes as bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::primality;
+///
+/// fn main() {
+///     println!("{}", primality::miller_rabin_rounds(65537, 8));
+/// }
+/// ```
+pub fn miller_rabin_rounds<T: Integer>(n: T, rounds: usize) -> bool {
+    assert!(rounds <= SMALL_PRIMES.len(), "at most 25 rounds");
+    let bases: Vec<T> = SMALL_PRIMES[..rounds]
+        .iter()
+        .filter_map(|&p| T::from_i64(p as i64))
+        .collect();
+    miller_rabin(n, &bases)
+}
+
+/// Deterministic Miller-Rabin.
+///
+/// ```text
+/// The first k prime bases are proven to decide all n below a bound,
+/// 13 bases (2..41) cover n < 3317044064679887385961981 (about 2^81).
+/// Returns None if n is beyond the proven bound.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::primality;
+///
+/// fn main() {
+///     println!("{:?}", primality::miller_rabin_deterministic(3215031751u64));
+/// }
+/// ```
+pub fn miller_rabin_deterministic<T: Integer>(n: T) -> Option<bool> {
+    let m = match lift(&n) {
+        Some(m) => m,
+        None => return Some(false),
+    };
+    let (_, k) = DETERMINISTIC_BOUNDS
+        .iter()
+        .find(|(bound, _)| m < BigUint::from(*bound))?;
+    Some(miller_rabin_rounds(n, *k))
+}
+
+/// Strong Lucas probable prime test with Selfridge's parameters.
+///
+/// ```text
+/// D is the first of 5, -7, 9, -11, ... with Jacobi(D/n) = -1,
+/// P = 1, Q = (1 - D) / 4
+/// n + 1 = d * 2^s, d odd
+/// n is a strong Lucas probable prime if
+/// U(d) = 0 (mod n), or
+/// V(d * 2^r) = 0 (mod n) for some 0 <= r < s
+///
+/// Doubling and increment of the Lucas sequences:
+/// U(2k) = U(k) * V(k)
+/// V(2k) = V(k)^2 - 2 * Q^k
+/// U(k+1) = (P * U(k) + V(k)) / 2
+/// V(k+1) = (D * U(k) + P * V(k)) / 2
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::primality;
+///
+/// fn main() {
+///     println!("{}", primality::strong_lucas(5459));
+/// }
+/// ```
+pub fn strong_lucas<T: Integer>(n: T) -> bool {
+    match lift(&n) {
+        Some(m) => match small_cases(&m) {
+            Some(prime) => prime,
+            None => strong_lucas_big(&m),
+        },
+        None => false,
+    }
+}
+
+/// Baillie-PSW primality test.
+///
+/// ```text
+/// 1. trial division by the primes below 100
+/// 2. Miller-Rabin to base 2
+/// 3. strong Lucas test
+/// No composite is known to pass, and there is none below 2^64.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::primality;
+///
+/// fn main() {
+///     println!("{}", primality::baillie_psw(1000000007));
+/// }
+/// ```
+pub fn baillie_psw<T: Integer>(n: T) -> bool {
+    let m = match lift(&n) {
+        Some(m) => m,
+        None => return false,
+    };
+    if m < BigUint::from(2u32) {
+        return false;
+    }
+    for &p in SMALL_PRIMES.iter() {
+        let p = BigUint::from(p);
+        if m == p {
+            return true;
+        }
+        if (&m % &p).is_zero() {
+            return false;
+        }
+    }
+
+    let ctx = MontgomeryContext::new(&m);
+    strong_probable_prime(&ctx, &BigUint::from(2u32)) && strong_lucas_big(&m)
+}
+
+/// Tests primality with Baillie-PSW.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::primality;
+///
+/// fn main() {
+///     println!("{}", primality::is_prime(37));
+/// }
+/// ```
+pub fn is_prime<T: Integer>(n: T) -> bool {
+    baillie_psw(n)
+}
+
+/// Non-negative n as BigUint.
+fn lift<T: Integer>(n: &T) -> Option<BigUint> {
+    n.to_bigint().to_biguint()
+}
+
+/// Decides n < 4 and even n, the tests below need an odd n > 3.
+fn small_cases(n: &BigUint) -> Option<bool> {
+    if *n < BigUint::from(4u32) {
+        Some(*n >= BigUint::from(2u32))
+    } else if n.is_even() {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Index of the lowest set bit, x must not be zero.
+fn trailing_zeros(x: &BigUint) -> usize {
+    (0..x.bits()).find(|&i| x.bit(i)).unwrap()
+}
+
+fn strong_probable_prime(ctx: &MontgomeryContext, a: &BigUint) -> bool {
+    let n = ctx.modulus();
+    let a = a % n;
+    if a.is_zero() {
+        return true;
+    }
+
+    let n1 = n - &BigUint::one();
+    let s = trailing_zeros(&n1);
+    let d = &n1 >> s;
+    let one = ctx.one();
+    let minus_one = n - &one;
+
+    let mut x = ctx.mont_exp(&ctx.to_mont(&a), &d);
+    if x == one || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = ctx.mont_mul(&x, &x);
+        if x == minus_one {
+            return true;
+        }
+    }
+    false
+}
+
+fn strong_lucas_big(n: &BigUint) -> bool {
+    // No D with Jacobi(D/n) = -1 exists for a square.
+    if is_square(n) {
+        return false;
+    }
+
+    let mut d: i64 = 5;
+    let dm = loop {
+        let dm = small_mod(d, n);
+        match symbol::jacobi(dm.clone(), n.clone()) {
+            Symbol::MinusOne => break dm,
+            Symbol::Zero if BigUint::from(d.unsigned_abs()) != *n => return false,
+            _ => {}
+        }
+        d = if d > 0 { -(d + 2) } else { -d + 2 };
+    };
+    let q = small_mod((1 - d) / 4, n);
+
+    let mul = |a: &BigUint, b: &BigUint| (a * b) % n;
+    let half = |a: BigUint| {
+        if a.is_even() {
+            a >> 1
+        } else {
+            (a + n) >> 1
+        }
+    };
+
+    // n + 1 = k * 2^s
+    let n1 = n + &BigUint::one();
+    let s = trailing_zeros(&n1);
+    let k = &n1 >> s;
+
+    let (mut u, mut v, mut qk) = (BigUint::one(), BigUint::one(), q.clone());
+    for i in (0..k.bits() - 1).rev() {
+        u = mul(&u, &v);
+        v = arith::mod_sub(mul(&v, &v), &qk + &qk, n.clone());
+        qk = mul(&qk, &qk);
+        if k.bit(i) {
+            let u1 = half(arith::mod_add(u.clone(), v.clone(), n.clone()));
+            let v1 = half(arith::mod_add(mul(&dm, &u), v, n.clone()));
+            u = u1;
+            v = v1;
+            qk = mul(&qk, &q);
+        }
+    }
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for _ in 1..s {
+        v = arith::mod_sub(mul(&v, &v), &qk + &qk, n.clone());
+        qk = mul(&qk, &qk);
+        if v.is_zero() {
+            return true;
+        }
+    }
+    false
+}
+
+/// d (mod n) for a small signed d.
+fn small_mod(d: i64, n: &BigUint) -> BigUint {
+    let r = BigUint::from(d.unsigned_abs()) % n;
+    if d < 0 && !r.is_zero() {
+        n - &r
+    } else {
+        r
+    }
+}
+
+fn is_square(n: &BigUint) -> bool {
+    let r = isqrt(n);
+    &r * &r == *n
+}
+
+/// floor(sqrt(n)) with Newton's iteration.
+fn isqrt(n: &BigUint) -> BigUint {
+    if n.is_zero() {
+        return BigUint::zero();
+    }
+    let mut x = BigUint::one() << (n.bits() / 2 + 1);
+    loop {
+        let y = (&x + &(n / &x)) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
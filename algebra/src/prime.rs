@@ -0,0 +1,231 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::bigint::BigUint;
+use crate::gcd;
+use crate::primality::{self, SMALL_PRIMES};
+use crate::rng::Rng;
+
+/// Generates a random odd prime of exactly bits bits.
+///
+/// ```text
+/// Pick a random odd number with the top bit set,
+/// keep it if it passes primality::is_prime, try again otherwise.
+/// About ln(2^bits) / 2 candidates are needed on average.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::prime;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(1);
+///     let p = prime::random_prime(&mut rng, 64);
+///     println!("{}", p);
+/// }
+/// ```
+pub fn random_prime<R: Rng>(rng: &mut R, bits: usize) -> BigUint {
+    // Bertrand: a prime lies in [2^(bits-1), 2^bits), the search ends.
+    loop {
+        if let Some(p) = random_prime_congruent(rng, bits, 1, 2) {
+            return p;
+        }
+    }
+}
+
+/// Generates a random prime of exactly bits bits with p = residue (mod modulus).
+///
+/// ```text
+/// Pick a random number x with the top bit set,
+/// p = x - (x mod modulus) + residue
+/// keep p if it still has bits bits and is a prime.
+/// Returns None if none of 64 * bits candidates is a prime, always the case
+/// when the class has no prime of bits bits, e.g. 1 (mod 4) with 2 bits.
+/// A candidate is a prime with probability about
+/// modulus / (phi(modulus) * bits * ln 2) > 1 / bits (Dirichlet's theorem),
+/// so a class with primes is missed with probability below e^-64.
+/// Panics if gcd(residue, modulus) != 1.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::prime;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(1);
+///     let p = prime::random_prime_congruent(&mut rng, 64, 5, 8);
+///     println!("{:?}", p);
+/// }
+/// ```
+pub fn random_prime_congruent<R: Rng>(
+    rng: &mut R,
+    bits: usize,
+    residue: u32,
+    modulus: u32,
+) -> Option<BigUint> {
+    assert!(bits >= 2, "a prime has at least 2 bits");
+    assert!(residue < modulus, "residue must be less than modulus");
+    assert!(
+        gcd::gcd(residue, modulus) == 1,
+        "residue and modulus must be coprime"
+    );
+    let m = BigUint::from(modulus);
+    for _ in 0..64 * bits {
+        let x = random_bits_exact(rng, bits);
+        let p = &x - &(&x % &m) + BigUint::from(residue);
+        if p.bits() == bits && primality::is_prime(p.clone()) {
+            return Some(p);
+        }
+    }
+    None
+}
+
+/// Generates a random prime p = 3 (mod 4), Field::sqrt takes a single exponentiation.
+///
+/// ```text
+/// sqrt(a) = a ^ ((p + 1) / 4) (mod p)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::prime;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(1);
+///     let p = prime::random_prime_3_mod_4(&mut rng, 64);
+///     println!("{}", p);
+/// }
+/// ```
+pub fn random_prime_3_mod_4<R: Rng>(rng: &mut R, bits: usize) -> BigUint {
+    // 3 has 2 bits, Breusch: (x, 2x] has a prime = 3 (mod 4) for x >= 7.
+    loop {
+        if let Some(p) = random_prime_congruent(rng, bits, 3, 4) {
+            return p;
+        }
+    }
+}
+
+/// Generates a random prime p = 1 (mod 4), -1 is a square in such a field.
+///
+/// ```text
+/// Panics if bits < 3, the least such prime is 5.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::prime;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(1);
+///     let p = prime::random_prime_1_mod_4(&mut rng, 64);
+///     println!("{}", p);
+/// }
+/// ```
+pub fn random_prime_1_mod_4<R: Rng>(rng: &mut R, bits: usize) -> BigUint {
+    assert!(bits >= 3, "a prime = 1 (mod 4) has at least 3 bits");
+    // 5 has 3 bits, Breusch: (x, 2x] has a prime = 1 (mod 4) for x >= 7.
+    loop {
+        if let Some(p) = random_prime_congruent(rng, bits, 1, 4) {
+            return p;
+        }
+    }
+}
+
+/// Generates a random safe prime p = 2q + 1 of exactly bits bits, q is a prime too.
+///
+/// ```text
+/// Sieve first, for every small prime s:
+/// q = 0 (mod s) means s | q
+/// q = (s - 1) / 2 (mod s) means s | 2q + 1
+/// Only the survivors go to the primality tests.
+/// q is odd, except for 3 bits where q = 2 gives the safe prime 5.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::prime;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(1);
+///     let p = prime::safe_prime(&mut rng, 32);
+///     println!("{}", p);
+/// }
+/// ```
+pub fn safe_prime<R: Rng>(rng: &mut R, bits: usize) -> BigUint {
+    assert!(bits >= 3, "a safe prime has at least 3 bits");
+    loop {
+        let q = if bits == 3 {
+            random_bits_exact(rng, 2)
+        } else {
+            random_odd(rng, bits - 1)
+        };
+        let p = (&q << 1) + BigUint::one();
+        let sieved = SMALL_PRIMES[1..].iter().any(|&s| {
+            let r = (&q % &BigUint::from(s)).to_u64().unwrap() as u32;
+            (r == 0 && q != BigUint::from(s)) || (r == (s - 1) / 2 && p != BigUint::from(s))
+        });
+        if !sieved && primality::is_prime(q.clone()) && primality::is_prime(p.clone()) {
+            return p;
+        }
+    }
+}
+
+/// Generates DSA domain primes (p, q), q | p - 1.
+///
+/// ```text
+/// q: random prime of n bits
+/// p: l bits, x random, p = x - (x mod 2q) + 1
+/// A new q is picked after 4 * l failed p candidates (FIPS 186-4 A.1.1.2).
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::prime;
+/// use algebra::rng::SplitMix64;
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(1);
+///     let (p, q) = prime::dsa_primes(&mut rng, 128, 32);
+///     println!("{} {}", p, q);
+/// }
+/// ```
+pub fn dsa_primes<R: Rng>(rng: &mut R, l: usize, n: usize) -> (BigUint, BigUint) {
+    assert!(l > n, "p must be longer than q");
+    loop {
+        let q = random_prime(rng, n);
+        let q2 = &q << 1;
+        for _ in 0..4 * l {
+            let x = random_bits_exact(rng, l);
+            let p = &x - &(&x % &q2) + BigUint::one();
+            if p.bits() == l && primality::is_prime(p.clone()) {
+                return (p, q);
+            }
+        }
+    }
+}
+
+/// Random number of exactly bits bits, the top bit is set.
+fn random_bits_exact<R: Rng>(rng: &mut R, bits: usize) -> BigUint {
+    rng.gen_bits(bits - 1) + (BigUint::one() << (bits - 1))
+}
+
+/// Random odd number of exactly bits bits.
+fn random_odd<R: Rng>(rng: &mut R, bits: usize) -> BigUint {
+    let x = random_bits_exact(rng, bits);
+    if x.is_even() {
+        x + BigUint::one()
+    } else {
+        x
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::bigint::BigUint;
+
+/// Source of random numbers.
+///
+/// Everything that needs randomness takes a &mut impl Rng,
+/// so a seeded generator makes the results reproducible.
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns a uniform random number in [0, 2^bits).
+    fn gen_bits(&mut self, bits: usize) -> BigUint {
+        let mut limbs: Vec<u32> = (0..bits / 32).map(|_| self.next_u32()).collect();
+        let top = bits % 32;
+        if top > 0 {
+            limbs.push(self.next_u32() & ((1u32 << top) - 1));
+        }
+        BigUint::from_limbs(&limbs)
+    }
+
+    /// Returns a uniform random number in [0, n) by rejection sampling.
+    fn gen_below(&mut self, n: &BigUint) -> BigUint {
+        assert!(!n.is_zero(), "upper bound must not be zero");
+        loop {
+            let x = self.gen_bits(n.bits());
+            if x < *n {
+                return x;
+            }
+        }
+    }
+
+    /// Returns a uniform random number in [low, high).
+    fn gen_range(&mut self, low: &BigUint, high: &BigUint) -> BigUint {
+        assert!(low < high, "empty range");
+        low + &self.gen_below(&(high - low))
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// SplitMix64 generator, small and fast, seeded with a u64.
+///
+/// ```text
+/// state = state + 0x9e3779b97f4a7c15
+/// z = state
+/// z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9
+/// z = (z ^ (z >> 27)) * 0x94d049bb133111eb
+/// return z ^ (z >> 31)
+/// ```
+///
+/// It is NOT a cryptographically secure generator.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::rng::{Rng, SplitMix64};
+///
+/// fn main() {
+///     let mut rng = SplitMix64::new(42);
+///     println!("{}", rng.next_u64());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
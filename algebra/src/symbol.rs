@@ -0,0 +1,151 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::mem;
+
+use crate::bigint::BigInt;
+use crate::{arith, Integer};
+
+/// Value of a Legendre, Jacobi or Kronecker symbol.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Symbol {
+    MinusOne,
+    Zero,
+    One,
+}
+
+impl Symbol {
+    /// Returns the symbol as -1, 0 or 1.
+    pub fn value(self) -> i8 {
+        match self {
+            Symbol::MinusOne => -1,
+            Symbol::Zero => 0,
+            Symbol::One => 1,
+        }
+    }
+
+    fn from_value(v: i8) -> Self {
+        match v {
+            -1 => Symbol::MinusOne,
+            0 => Symbol::Zero,
+            _ => Symbol::One,
+        }
+    }
+}
+
+impl From<Symbol> for i8 {
+    fn from(s: Symbol) -> i8 {
+        s.value()
+    }
+}
+
+/// Computes the Jacobi symbol (a/n) for an odd positive n.
+///
+/// ```text
+/// (a/n) = (a/p1)^e1 * ... * (a/pk)^ek, n = p1^e1 * ... * pk^ek
+/// For a prime n it is the Legendre symbol.
+/// (a/n) = -1 means a is not a square mod n,
+/// (a/n) = 1 does not mean a is a square if n is composite.
+/// ```
+///
+/// ```text
+/// Algorithm, quadratic reciprocity, no exponentiation:
+/// (2a/n) = (a/n), negated iff n = 3, 5 (mod 8)
+/// (a/n) = (n/a), negated iff a = n = 3 (mod 4), a and n odd
+/// (a/n) = ((a mod n)/n)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::symbol;
+///
+/// fn main() {
+///     let s = symbol::jacobi(1001, 9907);
+///     println!("{:?}", s);
+/// }
+/// ```
+pub fn jacobi<T: Integer>(a: T, n: T) -> Symbol {
+    assert!(
+        n.is_odd() && !n.is_negative(),
+        "Jacobi symbol needs an odd positive n"
+    );
+    let mut a = arith::reduce(a, n.clone());
+    let mut n = n;
+    let mut t = 1;
+    while !a.is_zero() {
+        while a.is_even() {
+            a = a >> 1;
+            // n = 3, 5 (mod 8)
+            if n.bit(1) != n.bit(2) {
+                t = -t;
+            }
+        }
+        mem::swap(&mut a, &mut n);
+        // a = n = 3 (mod 4)
+        if a.bit(1) && n.bit(1) {
+            t = -t;
+        }
+        a = a % n.clone();
+    }
+    if n.is_one() {
+        Symbol::from_value(t)
+    } else {
+        Symbol::Zero
+    }
+}
+
+/// Computes the Kronecker symbol (a/n) for any integer n.
+///
+/// ```text
+/// Extends the Jacobi symbol to even and negative n:
+/// (a/0)  = 1 if a = +-1, 0 otherwise
+/// (a/-1) = -1 if a < 0, 1 otherwise
+/// (a/2)  = 0 if a is even, 1 if a = +-1 (mod 8), -1 if a = +-3 (mod 8)
+/// n = +-2^v * m, m odd: (a/n) = (a/+-1) * (a/2)^v * (a/m)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::symbol;
+///
+/// fn main() {
+///     let s = symbol::kronecker(5, -12);
+///     println!("{:?}", s);
+/// }
+/// ```
+pub fn kronecker<T: Integer>(a: T, n: T) -> Symbol {
+    let (a, mut n) = (a.to_bigint(), n.to_bigint());
+    if n.is_zero() {
+        return if a.abs().is_one() {
+            Symbol::One
+        } else {
+            Symbol::Zero
+        };
+    }
+
+    let mut t = 1;
+    if n.is_negative() {
+        n = -n;
+        if a.is_negative() {
+            t = -t;
+        }
+    }
+
+    let mut v = 0;
+    while n.is_even() {
+        n = n >> 1;
+        v += 1;
+    }
+    if v > 0 {
+        if a.is_even() {
+            return Symbol::Zero;
+        }
+        let a8 = arith::reduce(a.clone(), BigInt::from(8));
+        if v % 2 == 1 && (a8 == BigInt::from(3) || a8 == BigInt::from(5)) {
+            t = -t;
+        }
+    }
+    Symbol::from_value(t * jacobi(a, n).value())
+}
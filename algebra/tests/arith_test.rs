@@ -4,6 +4,7 @@
 #[cfg(test)]
 pub mod tests {
     use algebra::arith;
+    use algebra::bigint::{BigInt, BigUint};
 
     #[test]
     fn arith_modadd_test() {
@@ -16,6 +17,11 @@ pub mod tests {
             let x = arith::mod_add(126, 126, 127);
             assert_eq!(x, 125);
         }
+
+        {
+            let x = arith::mod_add(100, 20, 127);
+            assert_eq!(x, 120);
+        }
     }
 
     #[test]
@@ -42,6 +48,18 @@ pub mod tests {
             let x = arith::mod_mul(25, 30, 37);
             assert_eq!(x, 10);
         }
+
+        {
+            let x = arith::mod_mul(100, 100, 127);
+            assert_eq!(x, 94);
+        }
+
+        {
+            // 2^61 - 1, the product needs 80 bits.
+            let m = (1u64 << 61) - 1;
+            let x = arith::mod_mul(123456789012, 987654321098, m);
+            assert_eq!(x, 1958652374963740247);
+        }
     }
 
     #[test]
@@ -91,5 +109,92 @@ pub mod tests {
             let x = arith::mod_exp(15, 2, 37);
             assert_eq!(x, 3);
         }
+
+        // Exponent 0.
+        {
+            assert_eq!(arith::mod_exp(15, 0, 37), 1);
+            assert_eq!(arith::mod_exp(0, 0, 37), 1);
+            assert_eq!(arith::mod_exp(15, 0, 1), 0);
+        }
+
+        // Negative exponent.
+        {
+            assert_eq!(arith::mod_exp(3, -1, 37), 25);
+            assert_eq!(arith::mod_exp(2, -3, 37), 14);
+        }
+
+        // The most negative exponent, -i8::MIN does not fit i8.
+        {
+            assert_eq!(arith::mod_exp(2i8, i8::MIN, 37), 9);
+            assert_eq!(arith::mod_exp_window(2i8, i8::MIN, 37, 3), 9);
+            assert_eq!(arith::mod_exp(3i64, i64::MIN, 101), 25);
+        }
+
+        {
+            let m = (1u64 << 61) - 1;
+            let x = arith::mod_exp(123456789, 987654321987654321, m);
+            assert_eq!(x, 665890164238674364);
+        }
+
+        // Fermat's little theorem with the Mersenne prime 2^127 - 1.
+        {
+            let p = (BigUint::one() << 127) - BigUint::one();
+            let e = &p - &BigUint::one();
+            let x = arith::mod_exp(BigUint::from(3u32), e, p.clone());
+            assert_eq!(x, BigUint::one());
+
+            let inv = arith::mod_inv(BigUint::from(2u32), p.clone());
+            assert_eq!(inv, (&p + &BigUint::one()) >> 1);
+        }
+    }
+
+    #[test]
+    fn arith_modexp_window_test() {
+        assert_eq!(arith::mod_exp_window(3, 1000, 37, 4), 34);
+        assert_eq!(arith::mod_exp_window(15, 0, 37, 3), 1);
+        assert_eq!(arith::mod_exp_window(3, -1, 37, 2), 25);
+
+        for w in 1..6 {
+            for e in 0..200 {
+                assert_eq!(arith::mod_exp_window(5, e, 97, w), arith::mod_exp(5, e, 97));
+            }
+        }
+
+        let p = (BigUint::one() << 127) - BigUint::one();
+        let e = BigUint::from(10u32).pow(30);
+        let x = arith::mod_exp_window(BigUint::from(7u32), e.clone(), p.clone(), 5);
+        assert_eq!(x, "77365983643526108570615817422012409412".parse().unwrap());
+        assert_eq!(x, arith::mod_exp(BigUint::from(7u32), e, p));
+    }
+
+    #[test]
+    fn arith_bigint_operands_test() {
+        // p = 2^127 - 1
+        let two = BigUint::from(2u32);
+        let p = two.pow(127) - BigUint::one();
+        {
+            let x = arith::mod_add(two.pow(126), two.pow(126), p.clone());
+            assert_eq!(x, BigUint::one());
+        }
+
+        {
+            let x = arith::mod_mul(two.pow(126), BigUint::from(4u32), p.clone());
+            assert_eq!(x, two);
+        }
+
+        {
+            let x = arith::mod_inv(BigUint::from(14u32), BigUint::from(37u32));
+            assert_eq!(x, BigUint::from(8u32));
+        }
+
+        {
+            let x = arith::mod_exp(two.clone(), BigUint::from(127u32), p.clone());
+            assert_eq!(x, BigUint::one());
+        }
+
+        {
+            let x = arith::mod_sub(BigInt::from(-5), BigInt::from(7), BigInt::from(37));
+            assert_eq!(x, BigInt::from(25));
+        }
     }
 }
@@ -0,0 +1,70 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::arith;
+    use algebra::barrett::BarrettReducer;
+    use algebra::bigint::BigUint;
+
+    #[test]
+    fn barrett_reduce_test() {
+        {
+            let br = BarrettReducer::new(&BigUint::from(37u32));
+            // mu = floor(2^64 / 37)
+            assert_eq!(br.mu(), &BigUint::from(u64::MAX / 37));
+            for x in (0..(1u64 << 40)).step_by(999_999_937) {
+                assert_eq!(br.reduce(&BigUint::from(x)), BigUint::from(x % 37));
+            }
+        }
+
+        {
+            // 2^127 - 1 and 2^128 - 159 as 4-limb moduli.
+            for m in [
+                (BigUint::one() << 127) - BigUint::one(),
+                (BigUint::one() << 128) - BigUint::from(159u32),
+            ] {
+                let br = BarrettReducer::new(&m);
+                let x = (BigUint::one() << 255) + BigUint::from(123456789u32);
+                assert_eq!(br.reduce(&x), &x % &m);
+                let y = (BigUint::one() << 256) - BigUint::one();
+                assert_eq!(br.reduce(&y), &y % &m);
+            }
+        }
+    }
+
+    #[test]
+    fn barrett_mul_exp_test() {
+        {
+            let br = BarrettReducer::new(&BigUint::from(37u32));
+            assert_eq!(
+                br.mul(&BigUint::from(25u32), &BigUint::from(30u32)),
+                BigUint::from(10u32)
+            );
+            assert_eq!(
+                br.exp(&BigUint::from(18u32), &BigUint::from(11u32)),
+                BigUint::from(17u32)
+            );
+        }
+
+        {
+            let m = BigUint::from_str_radix(
+                "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+                16,
+            )
+            .unwrap();
+            let br = BarrettReducer::new(&m);
+            let a = &m - &BigUint::from(12345u32);
+            let b = (BigUint::one() << 200) + BigUint::from(99u32);
+            assert_eq!(
+                br.mul(&a, &b),
+                arith::mod_mul(a.clone(), b.clone(), m.clone())
+            );
+            assert_eq!(br.exp(&a, &b), arith::mod_exp(a, b, m.clone()));
+            assert_eq!(
+                br.exp(&BigUint::from(7u32), &(&m - &BigUint::one())),
+                BigUint::one()
+            );
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::{BigInt, BigUint};
+
+    fn big(s: &str) -> BigUint {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn bigint_biguint_add_sub_test() {
+        let a = BigUint::from(u64::MAX);
+        let b = BigUint::from(1u32);
+        let c = &a + &b;
+        assert_eq!(c, big("18446744073709551616"));
+        assert_eq!(c.limbs(), &[0, 0, 1]);
+        assert_eq!(&c - &b, a);
+        assert_eq!(&c - &c, BigUint::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn bigint_biguint_sub_underflow_test() {
+        let _ = BigUint::from(1u32) - BigUint::from(2u32);
+    }
+
+    #[test]
+    fn bigint_biguint_mul_test() {
+        let a = (BigUint::one() << 128) + BigUint::one();
+        let b = BigUint::from(u64::MAX - 58);
+        assert_eq!(
+            &a * &b,
+            big("6277101735386680743759129774872297071781700350063419588549")
+        );
+
+        let x = big("123456789012345678901234567890123456789");
+        let y = big("987654321987654321");
+        assert_eq!(
+            &x * &y,
+            big("121932631246761163237311385323731138532360920590112635269")
+        );
+        assert_eq!(&x * &BigUint::zero(), BigUint::zero());
+    }
+
+    #[test]
+    fn bigint_biguint_divrem_test() {
+        {
+            let a = (BigUint::one() << 128) + BigUint::one();
+            let b = BigUint::from(u64::MAX - 58);
+            let (q, r) = a.divrem(&b);
+            assert_eq!(q, big("18446744073709551675"));
+            assert_eq!(r, BigUint::from(3482u32));
+        }
+
+        {
+            let x = big("123456789012345678901234567890123456789");
+            let y = big("987654321987654321");
+            assert_eq!(&x / &y, big("124999998748437501153"));
+            assert_eq!(&x % &y, big("142745764920524676"));
+        }
+
+        {
+            let a = BigUint::one() << 200;
+            let b = (BigUint::one() << 100) + BigUint::from(7u32);
+            let (q, r) = a.divrem(&b);
+            assert_eq!(q, big("1267650600228229401496703205369"));
+            assert_eq!(r, BigUint::from(49u32));
+        }
+
+        // q * d + r == n, r < d for a spread of operand sizes.
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed as u32
+        };
+        for i in 1..40 {
+            let n: Vec<u32> = (0..(i % 9 + 2)).map(|_| next()).collect();
+            let d: Vec<u32> = (0..(i % 4 + 1)).map(|_| next() >> (i % 31)).collect();
+            let n = BigUint::from_limbs(&n);
+            let d = BigUint::from_limbs(&d);
+            if d.is_zero() {
+                continue;
+            }
+            let (q, r) = n.divrem(&d);
+            assert!(r < d);
+            assert_eq!(&q * &d + &r, n);
+        }
+    }
+
+    #[test]
+    fn bigint_biguint_shift_test() {
+        let a = BigUint::from(0x8000_0001u32);
+        assert_eq!(&a << 1, BigUint::from(0x1_0000_0002u64));
+        assert_eq!(&a << 64, big("39614081275578912870481526784"));
+        assert_eq!((&a << 67) >> 67, a);
+        assert_eq!(&a >> 32, BigUint::zero());
+        assert_eq!(a.bits(), 32);
+        assert!(a.bit(31));
+        assert!(!a.bit(30));
+    }
+
+    #[test]
+    fn bigint_biguint_compare_test() {
+        let a = big("340282366920938463463374607431768211456");
+        let b = big("340282366920938463463374607431768211455");
+        assert!(a > b);
+        assert!(b < a);
+        assert!(BigUint::from(5u32) > BigUint::zero());
+        assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn bigint_biguint_format_test() {
+        let s = "340282366920938463463374607431768211457";
+        assert_eq!(big(s).to_string(), s);
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(
+            BigUint::from_str_radix("ffffffffffffffff", 16).unwrap(),
+            BigUint::from(u64::MAX)
+        );
+        assert!("12a".parse::<BigUint>().is_err());
+        assert_eq!(BigUint::from(1000u32).to_u64(), Some(1000));
+        assert_eq!((BigUint::one() << 64).to_u64(), None);
+    }
+
+    #[test]
+    fn bigint_bigint_test() {
+        let a = BigInt::from(-100);
+        let b = BigInt::from(7);
+
+        // Same semantics as i8..i128.
+        assert_eq!(&a / &b, BigInt::from(-100 / 7));
+        assert_eq!(&a % &b, BigInt::from(-100 % 7));
+        assert_eq!(&a + &b, BigInt::from(-93));
+        assert_eq!(&b - &a, BigInt::from(107));
+        assert_eq!(&a * &b, BigInt::from(-700));
+        assert_eq!(-&a, BigInt::from(100));
+        assert_eq!(&a >> 3, BigInt::from(-100 >> 3));
+        assert!(a < b);
+        assert!(BigInt::from(-200) < a);
+
+        assert_eq!(a.to_string(), "-100");
+        assert_eq!("-100".parse::<BigInt>().unwrap(), a);
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(BigInt::from(u128::MAX).to_i128(), None);
+        assert_eq!(&a + &BigInt::from(100), BigInt::zero());
+        assert!(!(&a + &BigInt::from(100)).is_negative());
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigInt;
+    use algebra::{arith, crt};
+
+    #[test]
+    fn crt_coprime_test() {
+        assert_eq!(crt::crt(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+        assert_eq!(crt::crt(&[-1, -1], &[4, 9]), Some((35, 36)));
+        assert_eq!(crt::crt::<i32>(&[], &[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_non_coprime_test() {
+        // gcd(6, 10) = 2 divides 8 - 2.
+        assert_eq!(crt::crt(&[2, 8], &[6, 10]), Some((8, 30)));
+        assert_eq!(crt::crt(&[3, 3, 3], &[4, 6, 8]), Some((3, 24)));
+
+        // x = 1 (mod 4) is odd, x = 2 (mod 6) is even.
+        assert_eq!(crt::crt(&[1, 2], &[4, 6]), None);
+    }
+
+    #[test]
+    fn crt_garner_test() {
+        assert_eq!(crt::mixed_radix(&[2, 3, 2], &[3, 5, 7]), vec![2, 2, 1]);
+        assert_eq!(crt::garner(&[2, 3, 2], &[3, 5, 7]), 23);
+
+        let moduli = [7i64, 11, 13, 17, 19];
+        for x in [0i64, 1, 12345, 323322].iter() {
+            let residues: Vec<i64> = moduli.iter().map(|m| x % m).collect();
+            assert_eq!(crt::garner(&residues, &moduli), *x);
+            assert_eq!(crt::crt(&residues, &moduli), Some((*x, 323323)));
+        }
+    }
+
+    #[test]
+    fn crt_rsa_test() {
+        // RSA decryption with CRT, p = 61, q = 53, e = 17, d = 2753.
+        let (p, q, d) = (61i64, 53i64, 2753i64);
+        let c = arith::mod_exp(65, 17, p * q);
+        let mp = arith::mod_exp(c, d % (p - 1), p);
+        let mq = arith::mod_exp(c, d % (q - 1), q);
+        assert_eq!(crt::garner(&[mp, mq], &[p, q]), 65);
+        assert_eq!(crt::crt(&[mp, mq], &[p, q]), Some((65, p * q)));
+    }
+
+    #[test]
+    fn crt_big_test() {
+        let moduli = [
+            BigInt::from(4294967291u64),
+            BigInt::from(4294967279u64),
+            BigInt::from(4294967231u64),
+        ];
+        let x: BigInt = "12345678901234567890123456".parse().unwrap();
+        let residues: Vec<BigInt> = moduli.iter().map(|m| &x % m).collect();
+        let m = &(&moduli[0] * &moduli[1]) * &moduli[2];
+        assert_eq!(crt::crt(&residues, &moduli), Some((x.clone(), m)));
+        assert_eq!(crt::garner(&residues, &moduli), x);
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::rng::{Rng, SplitMix64};
+    use algebra::{arith, ct};
+
+    #[test]
+    fn ct_select_test() {
+        assert_eq!(ct::select(1, 14, 35), 14);
+        assert_eq!(ct::select(0, 14, 35), 35);
+        assert_eq!(ct::select(1, u64::MAX, 0), u64::MAX);
+
+        let (mut a, mut b) = (14u64, 35u64);
+        ct::swap(0, &mut a, &mut b);
+        assert_eq!((a, b), (14, 35));
+        ct::swap(1, &mut a, &mut b);
+        assert_eq!((a, b), (35, 14));
+
+        assert_eq!(ct::eq(7, 7), 1);
+        assert_eq!(ct::eq(7, 8), 0);
+        assert_eq!(ct::eq(0, 1 << 63), 0);
+        assert_eq!(ct::lt(7, 8), 1);
+        assert_eq!(ct::lt(8, 7), 0);
+        assert_eq!(ct::lt(7, 7), 0);
+        assert_eq!(ct::lt(0, u64::MAX), 1);
+    }
+
+    #[test]
+    fn ct_modarith_test() {
+        assert_eq!(ct::mod_add(14, 35, 37), 12);
+        assert_eq!(ct::mod_sub(14, 35, 37), 16);
+        assert_eq!(ct::mod_mul(14, 35, 37), 9);
+        assert_eq!(ct::mod_exp(15, 33, 37), arith::mod_exp(15, 33, 37));
+        assert_eq!(ct::mod_exp(15, 0, 37), 1);
+        assert_eq!(ct::mod_exp(0, 0, 37), 1);
+        assert_eq!(ct::mod_exp(0, 5, 37), 0);
+        assert_eq!(ct::reduce(14, 37), 14);
+        assert_eq!(ct::reduce(37, 37), 0);
+        assert_eq!(ct::reduce(u64::MAX, 37), u64::MAX % 37);
+        assert_eq!(ct::reduce(u64::MAX, 1), 0);
+
+        // Against algebra::arith, up to the largest 63-bit prime.
+        let mut rng = SplitMix64::new(24);
+        for &m in [2u64, 3, 37, 1000003, (1 << 61) - 1, (1 << 63) - 25].iter() {
+            for _ in 0..50 {
+                let a = rng.next_u64() % m;
+                let b = rng.next_u64() % m;
+                let e = rng.next_u64();
+                assert_eq!(ct::mod_add(a, b, m), arith::mod_add(a, b, m));
+                assert_eq!(ct::mod_sub(a, b, m), arith::mod_sub(a, b, m));
+                assert_eq!(ct::mod_mul(a, b, m), arith::mod_mul(a, b, m));
+                assert_eq!(ct::mod_exp(a, e, m), arith::mod_exp(a, e, m));
+                assert_eq!(ct::reduce(e, m), e % m);
+            }
+            assert_eq!(ct::mod_add(m - 1, m - 1, m), m - 2);
+            assert_eq!(ct::mod_sub(0, m - 1, m), 1);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use algebra::factor;
+
+    #[test]
+    fn factor_trial_division_test() {
+        let (factors, rest) = factor::trial_division(360 * 1009, 100);
+        assert_eq!(factors, vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(rest, 1009);
+
+        let (factors, rest) = factor::trial_division(97u32, 100);
+        assert_eq!(factors, vec![(97, 1)]);
+        assert_eq!(rest, 1);
+
+        let (factors, rest) = factor::trial_division(1u8, 100);
+        assert!(factors.is_empty());
+        assert_eq!(rest, 1);
+    }
+
+    #[test]
+    fn factor_pollard_rho_test() {
+        assert_eq!(factor::pollard_rho(37), None);
+        assert_eq!(factor::pollard_rho(1024), Some(2));
+
+        let d = factor::pollard_rho(8051).unwrap();
+        assert!(d == 83 || d == 97);
+
+        // 2^64 + 1 = 274177 * 67280421310721
+        let n = (BigUint::one() << 64) + BigUint::one();
+        let d = factor::pollard_rho(n.clone()).unwrap();
+        assert!(d > BigUint::one() && d < n);
+        assert!((&n % &d).is_zero());
+    }
+
+    #[test]
+    fn factor_pollard_p_minus_1_test() {
+        // 1403 = 23 * 61, 23 - 1 = 2 * 11 is not 10-smooth, 61 - 1 = 2^2 * 3 * 5 is.
+        assert_eq!(factor::pollard_p_minus_1(1403, 10), Some(61));
+        assert_eq!(factor::pollard_p_minus_1(1403, 3), None);
+
+        // p - 1 = 2^3 * 3 * 5 * 7 * 11 * 13 * 17 * 19 * 23 is 100-smooth,
+        // q - 1 = 2 * 500000003 is not.
+        let p = 892371481u128;
+        let q = 1000000007u128;
+        assert_eq!(factor::pollard_p_minus_1(p * q, 100), Some(p));
+    }
+
+    #[test]
+    fn factor_factorize_test() {
+        assert_eq!(factor::factorize(1), vec![]);
+        assert_eq!(factor::factorize(32), vec![(2, 5)]);
+        assert_eq!(factor::factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factor::factorize(-1i8 as u8), vec![(3, 1), (5, 1), (17, 1)]);
+        assert_eq!(
+            factor::factorize(u64::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ]
+        );
+
+        // Large repeated factors.
+        let p = 1000000007u128;
+        let q = 998244353u128;
+        assert_eq!(factor::factorize(p * p * q), vec![(q, 1), (p, 2)]);
+
+        // 2^64 + 1 = 274177 * 67280421310721
+        let n = (BigUint::one() << 64) + BigUint::one();
+        assert_eq!(
+            factor::factorize(n),
+            vec![
+                (BigUint::from(274177u32), 1),
+                (BigUint::from(67280421310721u64), 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use std::time::Instant;
+
+    use algebra::rng::SplitMix64;
+    use algebra::{arith, index_calculus};
+
+    #[test]
+    fn index_calculus_smooth_test() {
+        assert_eq!(
+            index_calculus::factor_base(30),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+        assert_eq!(
+            index_calculus::smooth_exponents(360, &[2, 3, 5, 7]),
+            Some(vec![3, 2, 1, 0])
+        );
+        assert_eq!(index_calculus::smooth_exponents(22, &[2, 3, 5, 7]), None);
+        assert_eq!(index_calculus::smooth_exponents(1, &[2]), Some(vec![0]));
+    }
+
+    #[test]
+    fn index_calculus_factor_base_logs_test() {
+        // 2 is a primitive root mod 1019.
+        let (g, p) = (2u64, 1019u64);
+        let base = index_calculus::factor_base(20);
+        let mut rng = SplitMix64::new(1);
+        let logs = index_calculus::factor_base_logs(g, p, &base, &mut rng).unwrap();
+        assert_eq!(logs[0], 1);
+        for (q, l) in base.iter().zip(logs.iter()) {
+            assert_eq!(arith::mod_exp(g, *l, p), *q);
+        }
+    }
+
+    #[test]
+    fn index_calculus_dlog_test() {
+        let mut rng = SplitMix64::new(2020);
+        let (g, p) = (2u64, 1019u64);
+        for x in [0u64, 1, 10, 500, 1017].iter() {
+            let h = arith::mod_exp(g, *x, p);
+            assert_eq!(index_calculus::index_calculus(g, h, p, &mut rng), Some(*x));
+        }
+        assert_eq!(index_calculus::index_calculus(g, 0, p, &mut rng), None);
+
+        // 2^31 - 1, p - 1 = 2 * 3^2 * 7 * 11 * 31 * 151 * 331, 7 is a primitive root.
+        let (g, p) = (7u64, 2147483647u64);
+        let start = Instant::now();
+        let x = 1234567890u64;
+        let h = arith::mod_exp(g, x, p);
+        assert_eq!(index_calculus::index_calculus(g, h, p, &mut rng), Some(x));
+        println!("index calculus: 31-bit prime in {:?}", start.elapsed());
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::{BigInt, BigUint};
+    use algebra::{arith, gcd, Integer};
+
+    #[test]
+    fn integer_unsigned_test() {
+        // 200 + 100 would overflow u8.
+        assert_eq!(arith::mod_add(200u8, 100u8, 251u8), 49);
+        assert_eq!(arith::mod_sub(14u8, 15u8, 37u8), 36);
+        assert_eq!(arith::mod_mul(4u16, 16u16, 37u16), 27);
+        assert_eq!(arith::mod_inv(14u32, 37u32), 8);
+        assert_eq!(arith::mod_inv(2u8, 255u8), 128);
+        assert_eq!(arith::mod_div(4u64, 14u64, 37u64), 32);
+        assert_eq!(arith::mod_exp(18u128, 11u128, 37u128), 17);
+        assert_eq!(gcd::gcd(10u8, 25u8), 5);
+    }
+
+    #[test]
+    fn integer_signed_test() {
+        assert_eq!(arith::mod_add(-1i8, -1i8, 127i8), 125);
+        assert_eq!(arith::mod_sub(i64::MIN, 1, i64::MAX), i64::MAX - 2);
+        assert_eq!(arith::mod_inv(14i16, 37i16), 8);
+        assert_eq!(arith::reduce(-14i32, 37), 23);
+
+        let (g, x, y) = gcd::xgcd(240i64, 46i64);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, g);
+    }
+
+    #[test]
+    fn integer_big_test() {
+        // 2^127 - 1 is a Mersenne prime.
+        let p = (BigUint::one() << 127) - BigUint::one();
+        let a = &p - &BigUint::from(2u32);
+        let b = BigUint::from(5u32);
+        assert_eq!(
+            arith::mod_add(a.clone(), b.clone(), p.clone()),
+            BigUint::from(3u32)
+        );
+        assert_eq!(
+            arith::mod_sub(b.clone(), a.clone(), p.clone()),
+            BigUint::from(7u32)
+        );
+
+        let inv = arith::mod_inv(BigUint::from(2u32), BigUint::from(65537u32));
+        assert_eq!(inv, BigUint::from(32769u32));
+
+        let (g, x, y) = gcd::xgcd(BigInt::from(37), BigInt::from(14));
+        assert_eq!(g, BigInt::one());
+        assert_eq!((x, y), (BigInt::from(-3), BigInt::from(8)));
+
+        let q = BigInt::from(p.clone());
+        assert_eq!(
+            arith::mod_mul(BigInt::from(-2), BigInt::from(3), q.clone()),
+            q - BigInt::from(6)
+        );
+        assert_eq!(gcd::gcd(BigUint::from(10u32), p), BigUint::one());
+    }
+
+    #[test]
+    fn integer_conversion_test() {
+        assert_eq!(u8::from_i64(255), Some(255));
+        assert_eq!(i8::from_i64(128), None);
+        assert_eq!(BigUint::from_i64(-1), None);
+        assert_eq!(i8::from_bigint(&BigInt::from(-128)), Some(-128));
+        assert_eq!(u128::from_bigint(&u128::MAX.to_bigint()), Some(u128::MAX));
+        assert_eq!(37u32.bits(), 6);
+        assert!(37u32.bit(5));
+        assert!(BigUint::from(37u32).bit(2));
+    }
+
+    #[test]
+    fn integer_mul_mod_test() {
+        // Double-and-add for the fixed widths, a direct product for the big types.
+        let m = u64::MAX - 58;
+        let (a, b) = (m - 1, m - 2);
+        let expected = (a as u128 * b as u128 % m as u128) as u64;
+        assert_eq!(a.mul_mod(&b, &m), expected);
+        assert_eq!(
+            BigUint::from(a).mul_mod(&BigUint::from(b), &BigUint::from(m)),
+            BigUint::from(expected)
+        );
+        assert_eq!(
+            BigInt::from(a).mul_mod(&BigInt::from(b), &BigInt::from(m)),
+            BigInt::from(expected)
+        );
+        assert_eq!(14i8.mul_mod(&35, &37), 9);
+
+        // 2^126 * 4 = 2^128 = 2 (mod 2^127 - 1)
+        let p = (BigUint::one() << 127) - BigUint::one();
+        let x = arith::mod_mul(BigUint::one() << 126, BigUint::from(4u32), p);
+        assert_eq!(x, BigUint::from(2u32));
+    }
+}
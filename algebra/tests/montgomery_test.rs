@@ -0,0 +1,83 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::arith;
+    use algebra::bigint::BigUint;
+    use algebra::montgomery::MontgomeryContext;
+
+    fn p256() -> BigUint {
+        BigUint::from_str_radix(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            16,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn montgomery_context_test() {
+        let m = p256();
+        let ctx = MontgomeryContext::new(&m);
+
+        // R = 2^256, R mod m, R^2 mod m.
+        assert_eq!(ctx.r(), BigUint::one() << 256);
+        assert_eq!(ctx.one(), ctx.r() % &m);
+        assert_eq!(ctx.r2(), &((ctx.r() * ctx.r()) % &m));
+
+        // m * m' == -1 (mod b)
+        assert_eq!(m.limbs()[0].wrapping_mul(ctx.m_prime()), u32::MAX);
+    }
+
+    #[test]
+    fn montgomery_mul_test() {
+        {
+            let ctx = MontgomeryContext::new(&BigUint::from(37u32));
+            for a in 0..37u32 {
+                for b in 0..37u32 {
+                    let x = ctx.to_mont(&BigUint::from(a));
+                    let y = ctx.to_mont(&BigUint::from(b));
+                    let z = ctx.from_mont(&ctx.mont_mul(&x, &y));
+                    assert_eq!(z, BigUint::from(a * b % 37));
+                }
+            }
+        }
+
+        {
+            let m = p256();
+            let ctx = MontgomeryContext::new(&m);
+            let a = &m - &BigUint::from(12345u32);
+            let b = (BigUint::one() << 200) + BigUint::from(99u32);
+
+            assert_eq!(ctx.from_mont(&ctx.to_mont(&a)), a);
+            let z = ctx.from_mont(&ctx.mont_mul(&ctx.to_mont(&a), &ctx.to_mont(&b)));
+            assert_eq!(z, arith::mod_mul(a, b, m));
+        }
+    }
+
+    #[test]
+    fn montgomery_exp_test() {
+        {
+            let ctx = MontgomeryContext::new(&BigUint::from(37u32));
+            let x = ctx.to_mont(&BigUint::from(18u32));
+            let y = ctx.mont_exp(&x, &BigUint::from(11u32));
+            assert_eq!(ctx.from_mont(&y), BigUint::from(17u32));
+
+            let y = ctx.mont_exp(&x, &BigUint::zero());
+            assert_eq!(ctx.from_mont(&y), BigUint::one());
+        }
+
+        {
+            // Fermat's little theorem.
+            let m = p256();
+            let ctx = MontgomeryContext::new(&m);
+            let x = ctx.to_mont(&BigUint::from(7u32));
+            let y = ctx.mont_exp(&x, &(&m - &BigUint::one()));
+            assert_eq!(ctx.from_mont(&y), BigUint::one());
+
+            let e = (BigUint::one() << 255) + BigUint::from(12345u32);
+            let y = ctx.mont_exp(&x, &e);
+            assert_eq!(ctx.from_mont(&y), arith::mod_exp(BigUint::from(7u32), e, m));
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use algebra::primality;
+
+    #[test]
+    fn primality_small_factor_test() {
+        assert_eq!(primality::small_factor(91, 10), Some(7));
+        assert_eq!(primality::small_factor(97, 5), None);
+        assert_eq!(primality::small_factor(97, 100), Some(97));
+        assert_eq!(primality::small_factor(1, 100), None);
+        assert_eq!(primality::small_factor(251u8, 255), Some(251));
+
+        let primes: Vec<u32> = (0..50).filter(|&n| primality::is_prime_trial(n)).collect();
+        assert_eq!(
+            primes,
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]
+        );
+    }
+
+    #[test]
+    fn primality_miller_rabin_test() {
+        // 2047 = 23 * 89 is the smallest strong pseudoprime to base 2.
+        assert!(primality::miller_rabin(2047, &[2]));
+        assert!(!primality::miller_rabin(2047, &[2, 3]));
+        // 3215031751 fools the bases 2, 3, 5, 7.
+        assert!(primality::miller_rabin_rounds(3215031751u64, 4));
+        assert!(!primality::miller_rabin_rounds(3215031751u64, 5));
+        assert!(!primality::miller_rabin(-7, &[2]));
+
+        assert_eq!(
+            primality::miller_rabin_deterministic(3215031751u64),
+            Some(false)
+        );
+        assert_eq!(
+            primality::miller_rabin_deterministic(u64::MAX - 58),
+            Some(true)
+        );
+        let big = BigUint::one() << 100;
+        assert_eq!(primality::miller_rabin_deterministic(big), None);
+
+        for n in 0..2000u32 {
+            assert_eq!(
+                primality::miller_rabin_deterministic(n),
+                Some(primality::is_prime_trial(n))
+            );
+        }
+    }
+
+    #[test]
+    fn primality_strong_lucas_test() {
+        // The strong Lucas pseudoprimes below 10000.
+        for n in [5459u32, 5777, 10877].iter() {
+            assert!(primality::strong_lucas(*n));
+            assert!(!primality::is_prime_trial(*n));
+        }
+        // Squares and the small cases.
+        assert!(!primality::strong_lucas(49));
+        assert!(primality::strong_lucas(5));
+        assert!(primality::strong_lucas(2));
+
+        for n in 0..2000u32 {
+            if primality::is_prime_trial(n) {
+                assert!(primality::strong_lucas(n));
+            }
+        }
+    }
+
+    #[test]
+    fn primality_baillie_psw_test() {
+        for n in 0..3000i32 {
+            assert_eq!(primality::baillie_psw(n), primality::is_prime_trial(n));
+        }
+        // Carmichael numbers.
+        for n in [561u64, 41041, 825265, 321197185].iter() {
+            assert!(!primality::is_prime(*n));
+        }
+        assert!(primality::is_prime(1000000007u64));
+        assert!(primality::is_prime(u64::MAX - 58));
+        assert!(!primality::is_prime(u64::MAX));
+
+        let m127 = (BigUint::one() << 127) - BigUint::one();
+        let m128 = (BigUint::one() << 128) - BigUint::one();
+        assert!(primality::is_prime(m127.clone()));
+        assert!(primality::is_prime(i128::MAX));
+        assert!(!primality::is_prime(m128));
+
+        // 2^521 - 1 is a Mersenne prime, 2^523 - 1 is not.
+        let m521 = (BigUint::one() << 521) - BigUint::one();
+        let m523 = (BigUint::one() << 523) - BigUint::one();
+        assert!(primality::is_prime(m521));
+        assert!(!primality::is_prime(m523));
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use algebra::rng::SplitMix64;
+    use algebra::{primality, prime};
+
+    #[test]
+    fn prime_random_test() {
+        let mut rng = SplitMix64::new(2020);
+        for bits in [2usize, 8, 32, 64, 256].iter() {
+            let p = prime::random_prime(&mut rng, *bits);
+            assert_eq!(p.bits(), *bits);
+            assert!(primality::is_prime(p));
+        }
+
+        // Same seed, same prime.
+        let p1 = prime::random_prime(&mut SplitMix64::new(7), 128);
+        let p2 = prime::random_prime(&mut SplitMix64::new(7), 128);
+        assert_eq!(p1, p2);
+    }
+
+    #[test]
+    fn prime_congruent_test() {
+        let mut rng = SplitMix64::new(2020);
+        let four = BigUint::from(4u32);
+        for _ in 0..5 {
+            let p = prime::random_prime_3_mod_4(&mut rng, 64);
+            assert_eq!(&p % &four, BigUint::from(3u32));
+            assert!(primality::is_prime(p));
+
+            let p = prime::random_prime_1_mod_4(&mut rng, 64);
+            assert_eq!(&p % &four, BigUint::one());
+            assert!(primality::is_prime(p));
+        }
+
+        let p = prime::random_prime_congruent(&mut rng, 100, 5, 8).unwrap();
+        assert_eq!(p.bits(), 100);
+        assert_eq!(&p % &BigUint::from(8u32), BigUint::from(5u32));
+
+        // The only members of the class are tiny.
+        let p = prime::random_prime_congruent(&mut rng, 3, 1, 4);
+        assert_eq!(p, Some(BigUint::from(5u32)));
+        assert_eq!(
+            prime::random_prime_1_mod_4(&mut rng, 3),
+            BigUint::from(5u32)
+        );
+        assert_eq!(
+            prime::random_prime_3_mod_4(&mut rng, 2),
+            BigUint::from(3u32)
+        );
+        let p = prime::random_prime_congruent(&mut rng, 10, 9, 100).unwrap();
+        assert_eq!(p.bits(), 10);
+        assert_eq!(&p % &BigUint::from(100u32), BigUint::from(9u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "residue and modulus must be coprime")]
+    fn prime_congruent_coprime_test() {
+        prime::random_prime_congruent(&mut SplitMix64::new(2020), 8, 2, 4);
+    }
+
+    #[test]
+    fn prime_congruent_empty_class_test() {
+        // 1 (mod 4) has no member of 2 bits, 91 = 7 * 13 is the only one of
+        // 7 bits in 91 (mod 100).
+        let mut rng = SplitMix64::new(2020);
+        assert_eq!(prime::random_prime_congruent(&mut rng, 2, 1, 4), None);
+        assert_eq!(prime::random_prime_congruent(&mut rng, 7, 91, 100), None);
+    }
+
+    #[test]
+    fn prime_safe_test() {
+        let mut rng = SplitMix64::new(2020);
+        for bits in [3usize, 4, 16, 64].iter() {
+            let p = prime::safe_prime(&mut rng, *bits);
+            let q = (&p - &BigUint::one()) >> 1;
+            assert_eq!(p.bits(), *bits);
+            assert!(primality::is_prime(p));
+            assert!(primality::is_prime(q));
+        }
+    }
+
+    #[test]
+    fn prime_safe_small_test() {
+        // 5 = 2 * 2 + 1 and 7 = 2 * 3 + 1 are the safe primes of 3 bits.
+        let mut rng = SplitMix64::new(2020);
+        let mut seen = vec![];
+        for _ in 0..32 {
+            let p = prime::safe_prime(&mut rng, 3).to_u64().unwrap();
+            assert!(p == 5 || p == 7);
+            if !seen.contains(&p) {
+                seen.push(p);
+            }
+        }
+        seen.sort_unstable();
+        assert_eq!(seen, vec![5, 7]);
+    }
+
+    #[test]
+    fn prime_dsa_test() {
+        let mut rng = SplitMix64::new(2020);
+        let (p, q) = prime::dsa_primes(&mut rng, 256, 64);
+        assert_eq!(p.bits(), 256);
+        assert_eq!(q.bits(), 64);
+        assert!(primality::is_prime(p.clone()));
+        assert!(primality::is_prime(q.clone()));
+        assert!(((&p - &BigUint::one()) % q).is_zero());
+    }
+}
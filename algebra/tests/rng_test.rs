@@ -0,0 +1,46 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use algebra::rng::{Rng, SplitMix64};
+
+    #[test]
+    fn rng_splitmix_test() {
+        // Reference outputs of SplitMix64 seeded with 0.
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(rng.next_u64(), 0x6e789e6aa1b965f4);
+
+        let mut a = SplitMix64::new(7);
+        let mut b = SplitMix64::new(7);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn rng_gen_test() {
+        let mut rng = SplitMix64::new(1);
+        for bits in [0usize, 1, 31, 32, 33, 100].iter() {
+            for _ in 0..20 {
+                assert!(rng.gen_bits(*bits).bits() <= *bits);
+            }
+        }
+
+        let n = BigUint::from(10u32);
+        let mut seen = [false; 10];
+        for _ in 0..200 {
+            let x = rng.gen_below(&n);
+            seen[x.to_u64().unwrap() as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let (low, high) = (BigUint::from(1000u32), BigUint::from(1003u32));
+        for _ in 0..20 {
+            let x = rng.gen_range(&low, &high);
+            assert!(x >= low && x < high);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::arith;
+    use algebra::bigint::BigUint;
+    use algebra::symbol::{self, Symbol};
+
+    #[test]
+    fn symbol_jacobi_test() {
+        assert_eq!(symbol::jacobi(1001, 9907), Symbol::MinusOne);
+        assert_eq!(symbol::jacobi(19, 45), Symbol::One);
+        assert_eq!(symbol::jacobi(8, 21), Symbol::MinusOne);
+        assert_eq!(symbol::jacobi(5, 21), Symbol::One);
+        assert_eq!(symbol::jacobi(21, 21), Symbol::Zero);
+        assert_eq!(symbol::jacobi(6, 15), Symbol::Zero);
+        assert_eq!(symbol::jacobi(-1, 7), Symbol::MinusOne);
+        assert_eq!(symbol::jacobi(3u8, 1u8), Symbol::One);
+        assert_eq!(symbol::jacobi(-1i64, i64::MAX).value(), -1);
+
+        // (2/n) is a Jacobi symbol, but 2 is not a square mod 15.
+        assert_eq!(symbol::jacobi(2, 15), Symbol::One);
+
+        // Euler's criterion for primes.
+        for p in [3i64, 5, 7, 31, 37, 65537].iter() {
+            for a in 0..100 {
+                let e = arith::mod_exp(a, (p - 1) / 2, *p);
+                let expected = if e == 0 {
+                    0
+                } else if e == 1 {
+                    1
+                } else {
+                    -1
+                };
+                assert_eq!(i8::from(symbol::jacobi(a, *p)), expected);
+            }
+        }
+
+        let p = (BigUint::one() << 127) - BigUint::one();
+        assert_eq!(symbol::jacobi(BigUint::from(2u32), p.clone()), Symbol::One);
+        assert_eq!(
+            symbol::jacobi(p.clone() - BigUint::one(), p),
+            Symbol::MinusOne
+        );
+    }
+
+    #[test]
+    fn symbol_kronecker_test() {
+        assert_eq!(symbol::kronecker(1, 0), Symbol::One);
+        assert_eq!(symbol::kronecker(-1, 0), Symbol::One);
+        assert_eq!(symbol::kronecker(2, 0), Symbol::Zero);
+        assert_eq!(symbol::kronecker(-5, -1), Symbol::MinusOne);
+        assert_eq!(symbol::kronecker(5, -1), Symbol::One);
+        assert_eq!(symbol::kronecker(4, 2), Symbol::Zero);
+        assert_eq!(symbol::kronecker(7, 2), Symbol::One);
+        assert_eq!(symbol::kronecker(-3, 2), Symbol::MinusOne);
+        assert_eq!(symbol::kronecker(3, 8), Symbol::MinusOne);
+        assert_eq!(symbol::kronecker(3, 4), Symbol::One);
+        assert_eq!(symbol::kronecker(5, -12), Symbol::MinusOne);
+        assert_eq!(symbol::kronecker(-5, -12), Symbol::MinusOne);
+        assert_eq!(symbol::kronecker(i64::MIN, i64::MIN), Symbol::Zero);
+
+        // Same as Jacobi for odd positive n.
+        for n in (1..60).step_by(2) {
+            for a in -30..30 {
+                assert_eq!(symbol::kronecker(a, n), symbol::jacobi(a, n));
+            }
+        }
+    }
+
+    #[test]
+    fn symbol_value_test() {
+        assert_eq!(Symbol::MinusOne.value(), -1);
+        assert_eq!(i8::from(Symbol::Zero), 0);
+        assert_eq!(Symbol::One.value(), 1);
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+// Checks ClockCurve::scalar_mul for timing leaks, fixed vs random scalars.
+//
+// cargo run --release -p curves --example scalar_mul_timing [samples]
+
+use std::env;
+
+use algebra::rng::Rng;
+use curves::clockcurve;
+use dudect::{Class, Dudect};
+use fields::field;
+
+fn main() {
+    let samples = env::args()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(100_000);
+
+    let reductions = [field::Reduction::Plain, field::Reduction::ConstantTime];
+    for reduction in reductions.iter() {
+        let curve = clockcurve::ClockCurve::with_reduction(*reduction);
+        let report = Dudect::new(samples, 42).run(
+            |class, rng| match class {
+                Class::Fixed => 1i8,
+                Class::Random => (rng.next_u64() % 127) as i8 + 1,
+            },
+            |k| curve.scalar_mul(curve.base, *k),
+        );
+        println!("scalar_mul, {:?}: {}", reduction, report);
+    }
+}
@@ -1,9 +1,11 @@
 // Copyright (c) BohuTANG
 // Code is licensed with BSD
 
+use algebra::ct;
+use fields::element::FieldElement;
 use fields::field;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Point {
     pub x: i8,
     pub y: i8,
@@ -12,13 +14,13 @@ pub struct Point {
 /// Clock curve with Fp31.
 /// Equation:
 /// x^2 + y^2 = 1 over Fp31.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct ClockCurve {
     pub b: i8,
     pub prime: i8,
     pub base: Point,
     pub infinity: Point,
-    pub field: field::Field,
+    pub field: field::Field<i8>,
 }
 
 impl Default for ClockCurve {
@@ -34,6 +36,28 @@ impl Default for ClockCurve {
 }
 
 impl ClockCurve {
+    /// Returns the default curve whose field uses the given reduction,
+    /// Reduction::ConstantTime makes the point arithmetic constant time,
+    /// Reduction::Montgomery runs every product through mont_mul.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use curves::clockcurve;
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let curve = clockcurve::ClockCurve::with_reduction(field::Reduction::ConstantTime);
+    ///     let p2 = curve.scalar_mul(curve.base, 3);
+    ///     println!("{:?}", p2);
+    /// }
+    /// ```
+    pub fn with_reduction(reduction: field::Reduction) -> Self {
+        let mut curve = ClockCurve::default();
+        curve.field.set_reduction(reduction);
+        curve
+    }
+
     /// Returns the sum of (x1,y1) and (x2,y2).
     ///
     /// ```text
@@ -54,15 +78,15 @@ impl ClockCurve {
     ///     println!("{:?}", p3);
     /// }
     /// ```
-    pub fn scalar_add(self, p1: Point, p2: Point) -> Point {
-        let x1y2 = self.field.mul(p1.x, p2.y);
-        let x2y1 = self.field.mul(p2.x, p1.y);
-        let x3 = self.field.add(x1y2, x2y1);
-
-        let y2y1 = self.field.mul(p2.y, p1.y);
-        let x1x2 = self.field.mul(p1.x, p2.x);
-        let y3 = self.field.sub(y2y1, x1x2);
-        Point { x: x3, y: y3 }
+    pub fn scalar_add(&self, p1: Point, p2: Point) -> Point {
+        let (x1, y1) = (self.element(p1.x), self.element(p1.y));
+        let (x2, y2) = (self.element(p2.x), self.element(p2.y));
+        let x3 = x1 * y2 + x2 * y1;
+        let y3 = y2 * y1 - x1 * x2;
+        Point {
+            x: x3.value(),
+            y: y3.value(),
+        }
     }
 
     /// Returns the sum of (x1,y1) and (x2,-y2).
@@ -80,7 +104,7 @@ impl ClockCurve {
     ///     println!("{:?}", p3);
     /// }
     /// ```
-    pub fn scalar_sub(self, p1: Point, p2: Point) -> Point {
+    pub fn scalar_sub(&self, p1: Point, p2: Point) -> Point {
         self.scalar_add(p1, self.point_neg(p2))
     }
 
@@ -98,9 +122,9 @@ impl ClockCurve {
     ///     println!("{:?}", pneg);
     /// }
     /// ```
-    pub fn point_neg(self, p1: Point) -> Point {
+    pub fn point_neg(&self, p1: Point) -> Point {
         Point {
-            x: self.field.sub(self.prime, p1.x),
+            x: (-self.element(p1.x)).value(),
             y: p1.y,
         }
     }
@@ -119,13 +143,25 @@ impl ClockCurve {
     ///     println!("{:?}", pp);
     /// }
     /// ```
-    pub fn scalar_double(self, p: Point) -> Point {
+    pub fn scalar_double(&self, p: Point) -> Point {
         self.scalar_add(p, p)
     }
 
     /// Returns k*(x1,y1) where k is interge using Montgomery ladder for constant time.
     /// https://en.wikipedia.org/wiki/Elliptic_curve_point_multiplication#Montgomery_ladder
     ///
+    /// ```text
+    /// r0 = O, r1 = p, invariant r1 = r0 + p
+    /// for all 7 value bits of k, leading zeros included:
+    ///     swap(bit, r0, r1)
+    ///     r1 = r0 + r1, r0 = 2 * r0
+    ///     swap(bit, r0, r1)
+    /// The swaps are branchless (algebra::ct), every step does one addition
+    /// and one doubling, k <= 0 gives O.
+    /// The field operations are only constant time with Reduction::ConstantTime,
+    /// see ClockCurve::with_reduction.
+    /// ```
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -138,26 +174,22 @@ impl ClockCurve {
     ///     println!("{:?}", p2);
     /// }
     /// ```
-    pub fn scalar_mul(self, p: Point, k: i8) -> Point {
+    pub fn scalar_mul(&self, p: Point, k: i8) -> Point {
         let mut r0 = self.infinity;
-        let mut r1 = Point { x: p.x, y: p.y };
+        let mut r1 = p;
 
-        let mut bits = 0;
-        let mut k1 = k;
-        while k1 > 0 {
-            bits += 1;
-            k1 >>= 1;
+        for i in (0..7).rev() {
+            let bit = ((k >> i) & 0x01) as u64;
+            Self::swap(bit, &mut r0, &mut r1);
+            r1 = self.scalar_add(r0, r1);
+            r0 = self.scalar_double(r0);
+            Self::swap(bit, &mut r0, &mut r1);
         }
 
-        for i in (0..bits).rev() {
-            if (k >> i) & 0x01 == 0 {
-                r1 = self.scalar_add(r0, r1);
-                r0 = self.scalar_double(r0);
-            } else {
-                r0 = self.scalar_add(r0, r1);
-                r1 = self.scalar_double(r1);
-            }
-        }
+        // The sign bit of k.
+        let negative = (k as u8 >> 7) as u64;
+        let mut infinity = self.infinity;
+        Self::swap(negative, &mut r0, &mut infinity);
         r0
     }
 
@@ -175,7 +207,7 @@ impl ClockCurve {
     ///     println!("{:?}", p2);
     /// }
     /// ```
-    pub fn scalar_basemul(self, k: i8) -> Point {
+    pub fn scalar_basemul(&self, k: i8) -> Point {
         self.scalar_mul(self.base, k)
     }
 
@@ -193,10 +225,9 @@ impl ClockCurve {
     ///     println!("{:?}", res);
     /// }
     /// ```
-    pub fn is_on_curve(self, p: Point) -> bool {
-        let xx = self.field.mul(p.x, p.x);
-        let yy = self.field.mul(p.y, p.y);
-        self.field.add(xx, yy) == self.b
+    pub fn is_on_curve(&self, p: Point) -> bool {
+        let (x, y) = (self.element(p.x), self.element(p.y));
+        x * x + y * y == self.element(self.b)
     }
 
     ///  Returns y coordinate if exists, otherwise None.
@@ -212,9 +243,60 @@ impl ClockCurve {
     ///     println!("{:?}", y);
     /// }
     /// ```
-    pub fn y(self, x: i8) -> Option<i8> {
-        let xx = self.field.mul(x, x);
-        let yy = self.prime - xx + self.b;
-        self.field.sqrt(yy)
+    pub fn y(&self, x: i8) -> Option<i8> {
+        let x = self.element(x);
+        let yy = self.element(self.b) - x * x;
+        yy.sqrt().map(|y| y.value())
+    }
+
+    ///  Returns the point with the x coordinate and the parity of y, otherwise None.
+    ///
+    /// ```text
+    /// A point (x, y) is compressed to (x, y is odd),
+    /// y and p - y have different parities.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use curves::clockcurve;
+    ///
+    /// fn main() {
+    ///     let curve = clockcurve::ClockCurve::default();
+    ///     let p = curve.decompress(2, false);
+    ///     println!("{:?}", p);
+    /// }
+    /// ```
+    pub fn decompress(&self, x: i8, odd: bool) -> Option<Point> {
+        let y = self.y(x)?;
+        if y == 0 && odd {
+            return None;
+        }
+        let y = if (y & 1 == 1) == odd {
+            y
+        } else {
+            (-self.element(y)).value()
+        };
+        Some(Point { x, y })
+    }
+
+    fn element(&self, a: i8) -> FieldElement<'_, i8> {
+        self.field.element(a)
+    }
+
+    /// Swaps the points if choice is 1, without branching on choice.
+    fn swap(choice: u64, a: &mut Point, b: &mut Point) {
+        let (mut ax, mut bx) = (a.x as u8 as u64, b.x as u8 as u64);
+        let (mut ay, mut by) = (a.y as u8 as u64, b.y as u8 as u64);
+        ct::swap(choice, &mut ax, &mut bx);
+        ct::swap(choice, &mut ay, &mut by);
+        *a = Point {
+            x: ax as u8 as i8,
+            y: ay as u8 as i8,
+        };
+        *b = Point {
+            x: bx as u8 as i8,
+            y: by as u8 as i8,
+        };
     }
 }
@@ -3,7 +3,7 @@
 
 use crate::clockcurve;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct PublicKey {
     pub point: clockcurve::Point,
     pub curve: clockcurve::ClockCurve,
@@ -26,16 +26,15 @@ impl PublicKey {
     ///     let publickey = privatekey.publickey();
     ///     println!("{:?}", publickey.serialize());
     /// }
-    pub fn serialize(self) -> [i8; 2] {
+    pub fn serialize(&self) -> [i8; 2] {
         [self.point.x, self.point.y]
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct PrivateKey {
     pub key: i8,
     publickey: PublicKey,
-    curve: clockcurve::ClockCurve,
 }
 
 impl PrivateKey {
@@ -44,7 +43,6 @@ impl PrivateKey {
         let p = cuv31.scalar_basemul(k);
         PrivateKey {
             key: k,
-            curve: cuv31,
             publickey: PublicKey {
                 point: p,
                 curve: cuv31,
@@ -53,7 +51,7 @@ impl PrivateKey {
     }
 
     pub fn publickey(&self) -> PublicKey {
-        self.publickey
+        self.publickey.clone()
     }
 
     /// Returns the serialize format of the private key.
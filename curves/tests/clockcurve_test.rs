@@ -3,7 +3,11 @@
 
 #[cfg(test)]
 pub mod tests {
+    use algebra::rng::Rng;
     use curves::clockcurve;
+    use dudect::{self, Class, Dudect};
+    use fields::field;
+    use fields::fp2::Fp2;
 
     #[test]
     fn curves_clockcurve_scalar_add_test() {
@@ -189,12 +193,12 @@ pub mod tests {
         {
             let p1 = clockcurve::Point { x: 2, y: 20 };
             let res = clockcurve.is_on_curve(p1);
-            assert_eq!(res, true);
+            assert!(res);
         }
         {
             let p1 = clockcurve::Point { x: 3, y: 20 };
             let res = clockcurve.is_on_curve(p1);
-            assert_eq!(res, false);
+            assert!(!res);
         }
     }
 
@@ -206,15 +210,66 @@ pub mod tests {
         assert_eq!(y.unwrap(), 0);
     }
 
+    #[test]
+    fn curves_clockcurve_other_primes_test() {
+        // 41 = 1 (mod 8) needs Tonelli-Shanks, 37 = 5 (mod 8) Atkin.
+        for p in [41i8, 37, 127].iter() {
+            let curve = clockcurve::ClockCurve {
+                b: 1,
+                prime: *p,
+                base: clockcurve::Point { x: 0, y: 1 },
+                infinity: clockcurve::Point { x: 0, y: 1 },
+                field: field::Field::new(*p),
+            };
+            let mut points = 0;
+            for x in 0..*p {
+                if let Some(y) = curve.y(x) {
+                    assert!(curve.is_on_curve(clockcurve::Point { x, y }));
+                    points += if y == 0 { 1 } else { 2 };
+                }
+            }
+            // x^2 + y^2 = 1 has p - (-1|p) points.
+            let expected = if *p % 4 == 1 {
+                *p as i32 - 1
+            } else {
+                *p as i32 + 1
+            };
+            assert_eq!(points, expected);
+        }
+    }
+
+    #[test]
+    fn curves_clockcurve_decompress_test() {
+        let curve = clockcurve::ClockCurve::default();
+        for x in 0..31 {
+            for odd in [false, true].iter() {
+                if let Some(p) = curve.decompress(x, *odd) {
+                    assert_eq!(p.x, x);
+                    assert_eq!(p.y & 1 == 1, *odd);
+                    assert!(curve.is_on_curve(p));
+                }
+            }
+        }
+        assert_eq!(
+            curve.decompress(2, false),
+            Some(clockcurve::Point { x: 2, y: 20 })
+        );
+        assert_eq!(
+            curve.decompress(2, true),
+            Some(clockcurve::Point { x: 2, y: 11 })
+        );
+        assert_eq!(curve.decompress(1, true), None);
+        assert_eq!(curve.decompress(3, false), None);
+    }
+
     #[test]
     fn curves_clockcurve_element_test() {
         let clockcurve = clockcurve::ClockCurve::default();
 
         for i in 0..31 {
-            let p3 = clockcurve.y(i);
-            if p3 != None {
-                println!("({},{})", i, p3.unwrap());
-                println!("({},{})", i, clockcurve.prime - p3.unwrap());
+            if let Some(y) = clockcurve.y(i) {
+                println!("({},{})", i, y);
+                println!("({},{})", i, clockcurve.prime - y);
             }
         }
         /*
@@ -254,4 +309,98 @@ pub mod tests {
         (30,31)
         */
     }
+
+    #[test]
+    fn curves_clockcurve_fp2_test() {
+        // y returns None when the root is not in Fp31, it always exists in Fp2.
+        let curve = clockcurve::ClockCurve::default();
+        let fp2 = Fp2::new(field::Field::new(31i8), -1);
+        let mut missing = 0;
+        for x in 0..31i8 {
+            let yy = fp2.from_base(curve.field.sub(1, curve.field.mul(x, x)));
+            let y = fp2.sqrt(&yy).unwrap();
+            assert_eq!(fp2.square(&y), yy);
+            match curve.y(x) {
+                Some(y0) => {
+                    assert_eq!(y.c1, 0);
+                    assert!(y.c0 == y0 || y.c0 == curve.field.sub(0, y0));
+                }
+                None => {
+                    assert_eq!(y.c0, 0);
+                    missing += 1;
+                }
+            }
+        }
+        // 32 points over Fp31: x = +-1 with y = 0, 15 more x with two y each.
+        assert_eq!(missing, 31 - 17);
+    }
+
+    #[test]
+    fn curves_clockcurve_constant_time_test() {
+        let fast = clockcurve::ClockCurve::default();
+        let ct = clockcurve::ClockCurve::with_reduction(field::Reduction::ConstantTime);
+        assert_eq!(ct.field.reduction(), field::Reduction::ConstantTime);
+
+        let points: Vec<clockcurve::Point> = (0..31)
+            .filter_map(|x| ct.y(x).map(|y| clockcurve::Point { x, y }))
+            .collect();
+        for p in points.iter() {
+            for k in i8::MIN..=i8::MAX {
+                assert_eq!(ct.scalar_mul(*p, k), fast.scalar_mul(*p, k));
+            }
+            // k <= 0 gives the infinity.
+            assert_eq!(ct.scalar_mul(*p, 0), ct.infinity);
+            assert_eq!(ct.scalar_mul(*p, -3), ct.infinity);
+            assert_eq!(ct.scalar_mul(*p, 1), *p);
+        }
+
+        // Naive repeated addition agrees with the ladder.
+        let mut q = ct.infinity;
+        for k in 0..=i8::MAX {
+            assert_eq!(ct.scalar_mul(ct.base, k), q);
+            q = ct.scalar_add(q, ct.base);
+        }
+    }
+
+    #[test]
+    fn curves_clockcurve_reduction_test() {
+        // The point arithmetic runs on the field's Montgomery or Barrett context.
+        let fast = clockcurve::ClockCurve::default();
+        let mont = clockcurve::ClockCurve::with_reduction(field::Reduction::Montgomery);
+        let barrett = clockcurve::ClockCurve::with_reduction(field::Reduction::Barrett);
+        assert!(mont.field.montgomery().is_some());
+        assert!(barrett.field.barrett().is_some());
+        for k in 0..=i8::MAX {
+            let p = fast.scalar_basemul(k);
+            assert_eq!(mont.scalar_basemul(k), p);
+            assert_eq!(barrett.scalar_basemul(k), p);
+            assert!(mont.is_on_curve(p));
+        }
+    }
+
+    #[test]
+    fn curves_clockcurve_timing_test() {
+        // Fixed vs random scalars, see examples/scalar_mul_timing.rs for a long run.
+        // The plain backend leaks by far, |t| in the tens; the constant-time one
+        // stays around 1, twice the threshold leaves room for noisy machines.
+        let reductions = [field::Reduction::Plain, field::Reduction::ConstantTime];
+        for reduction in reductions.iter() {
+            let curve = clockcurve::ClockCurve::with_reduction(*reduction);
+            let report = Dudect::new(2000, 42).run(
+                |class, rng| match class {
+                    Class::Fixed => 1i8,
+                    Class::Random => (rng.next_u64() % 127) as i8 + 1,
+                },
+                |k| curve.scalar_mul(curve.base, *k),
+            );
+            println!("scalar_mul, {:?}: {}", reduction, report);
+            assert_eq!(report.fixed + report.random, 2000);
+            match reduction {
+                field::Reduction::ConstantTime => {
+                    assert!(report.t.abs() < 2.0 * dudect::THRESHOLD)
+                }
+                _ => assert!(report.leak()),
+            }
+        }
+    }
 }
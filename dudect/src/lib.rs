@@ -0,0 +1,259 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::Instant;
+
+use algebra::rng::{Rng, SplitMix64};
+
+/// |t| above this value reports a leak, the threshold of dudect.
+pub const THRESHOLD: f64 = 4.5;
+
+/// Percentiles the measurements are cropped at, besides the uncropped set.
+/// Large outliers (interrupts, preemption) hide small differences,
+/// the cropped sets only keep the fast, less noisy part.
+const CROPS: [f64; 5] = [0.5, 0.75, 0.9, 0.95, 0.99];
+
+/// Input class of a measurement.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Class {
+    Fixed,
+    Random,
+}
+
+/// Welch's t-test, the statistics are accumulated online with Welford's method.
+///
+/// ```text
+/// t = (mean0 - mean1) / sqrt(var0 / n0 + var1 / n1)
+/// var is the unbiased sample variance of each class.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use dudect::{Class, WelchTTest};
+///
+/// fn main() {
+///     let mut t = WelchTTest::new();
+///     for x in [1.0, 2.0, 3.0].iter() {
+///         t.push(Class::Fixed, *x);
+///         t.push(Class::Random, *x + 10.0);
+///     }
+///     println!("{}", t.t());
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WelchTTest {
+    n: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl WelchTTest {
+    pub fn new() -> Self {
+        WelchTTest::default()
+    }
+
+    /// Adds a measurement x of the class.
+    pub fn push(&mut self, class: Class, x: f64) {
+        let i = class as usize;
+        self.n[i] += 1.0;
+        let delta = x - self.mean[i];
+        self.mean[i] += delta / self.n[i];
+        self.m2[i] += delta * (x - self.mean[i]);
+    }
+
+    /// Returns the number of measurements of the class.
+    pub fn count(&self, class: Class) -> usize {
+        self.n[class as usize] as usize
+    }
+
+    /// Returns the mean of the class.
+    pub fn mean(&self, class: Class) -> f64 {
+        self.mean[class as usize]
+    }
+
+    /// Returns the sample variance of the class.
+    pub fn variance(&self, class: Class) -> f64 {
+        let i = class as usize;
+        if self.n[i] < 2.0 {
+            return 0.0;
+        }
+        self.m2[i] / (self.n[i] - 1.0)
+    }
+
+    /// Returns the t statistic, 0 until each class has 2 measurements,
+    /// +-infinity if both classes are constant but differ.
+    pub fn t(&self) -> f64 {
+        if self.n[0] < 2.0 || self.n[1] < 2.0 {
+            return 0.0;
+        }
+        let se = (self.variance(Class::Fixed) / self.n[0]
+            + self.variance(Class::Random) / self.n[1])
+            .sqrt();
+        let diff = self.mean[0] - self.mean[1];
+        if se == 0.0 {
+            return if diff == 0.0 {
+                0.0
+            } else {
+                diff.signum() * f64::INFINITY
+            };
+        }
+        diff / se
+    }
+}
+
+/// Result of a timing-leak test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// The t statistic with the largest |t| over all crops.
+    pub t: f64,
+    /// Measurements of the fixed and random class, uncropped.
+    pub fixed: usize,
+    pub random: usize,
+    /// Mean time of the fixed and random class in nanoseconds, uncropped.
+    pub fixed_mean: f64,
+    pub random_mean: f64,
+}
+
+impl Report {
+    /// Returns true if |t| exceeds THRESHOLD.
+    pub fn leak(&self) -> bool {
+        self.t.abs() > THRESHOLD
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "t = {:.2}, n = {}/{}, mean = {:.0}ns/{:.0}ns, {}",
+            self.t,
+            self.fixed,
+            self.random,
+            self.fixed_mean,
+            self.random_mean,
+            if self.leak() {
+                "leak detected"
+            } else {
+                "no leak detected"
+            }
+        )
+    }
+}
+
+/// dudect-style timing-leak detection.
+///
+/// ```text
+/// 1. prepare an input of a random class (fixed or random) per measurement,
+///    all inputs are prepared before timing starts
+/// 2. time the operation on each input in the shuffled order
+/// 3. Welch's t-test between the classes, on all measurements and on the
+///    measurements cropped at some percentiles, the largest |t| is reported
+/// A constant-time operation gives |t| around 1, leaks grow with the samples.
+/// ```
+///
+/// Reference: Reparaz, Balasch, Verbauwhede, "Dude, is my code constant time?", 2017.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::rng::Rng;
+/// use dudect::{Class, Dudect};
+///
+/// fn main() {
+///     let dudect = Dudect::new(1000, 42);
+///     let report = dudect.run(
+///         |class, rng| match class {
+///             Class::Fixed => 0u64,
+///             Class::Random => rng.next_u64(),
+///         },
+///         |x| x.count_ones(),
+///     );
+///     println!("{}", report);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dudect {
+    samples: usize,
+    seed: u64,
+}
+
+impl Dudect {
+    /// Creates a test with the number of measurements and the seed of
+    /// the class and input generator.
+    pub fn new(samples: usize, seed: u64) -> Self {
+        Dudect { samples, seed }
+    }
+
+    /// Times op on inputs from prepare and runs the t-test.
+    pub fn run<I, R, P, F>(&self, mut prepare: P, mut op: F) -> Report
+    where
+        P: FnMut(Class, &mut SplitMix64) -> I,
+        F: FnMut(&I) -> R,
+    {
+        let mut rng = SplitMix64::new(self.seed);
+        let inputs: Vec<(Class, I)> = (0..self.samples)
+            .map(|_| {
+                let class = if rng.next_u64() & 1 == 0 {
+                    Class::Fixed
+                } else {
+                    Class::Random
+                };
+                (class, prepare(class, &mut rng))
+            })
+            .collect();
+
+        // Warm up caches and branch predictors.
+        for (_, input) in inputs.iter().take(self.samples / 10) {
+            black_box(op(black_box(input)));
+        }
+
+        let timings: Vec<(Class, f64)> = inputs
+            .iter()
+            .map(|(class, input)| {
+                let start = Instant::now();
+                black_box(op(black_box(input)));
+                (*class, start.elapsed().as_nanos() as f64)
+            })
+            .collect();
+        Self::analyze(&timings)
+    }
+
+    /// Runs the t-test on the timings, uncropped and cropped.
+    pub fn analyze(timings: &[(Class, f64)]) -> Report {
+        let mut sorted: Vec<f64> = timings.iter().map(|(_, x)| *x).collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let mut cutoffs = vec![f64::INFINITY];
+        if !sorted.is_empty() {
+            for q in CROPS.iter() {
+                cutoffs.push(sorted[((sorted.len() - 1) as f64 * q) as usize]);
+            }
+        }
+
+        let tests: Vec<WelchTTest> = cutoffs
+            .iter()
+            .map(|&cutoff| {
+                let mut test = WelchTTest::new();
+                for &(class, x) in timings.iter().filter(|(_, x)| *x <= cutoff) {
+                    test.push(class, x);
+                }
+                test
+            })
+            .collect();
+
+        let all = &tests[0];
+        let t = tests
+            .iter()
+            .map(|test| test.t())
+            .fold(0.0, |t: f64, x| if x.abs() > t.abs() { x } else { t });
+        Report {
+            t,
+            fixed: all.count(Class::Fixed),
+            random: all.count(Class::Random),
+            fixed_mean: all.mean(Class::Fixed),
+            random_mean: all.mean(Class::Random),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use std::hint::black_box;
+
+    use algebra::rng::Rng;
+    use dudect::{self, Class, Dudect, WelchTTest};
+
+    #[test]
+    fn dudect_welch_test() {
+        let mut t = WelchTTest::new();
+        assert_eq!(t.t(), 0.0);
+        for x in [1.0, 2.0, 3.0, 4.0].iter() {
+            t.push(Class::Fixed, *x);
+        }
+        for x in [2.0, 4.0, 6.0].iter() {
+            t.push(Class::Random, *x);
+        }
+        assert_eq!(t.count(Class::Fixed), 4);
+        assert_eq!(t.count(Class::Random), 3);
+        assert!((t.mean(Class::Fixed) - 2.5).abs() < 1e-12);
+        assert!((t.variance(Class::Fixed) - 5.0 / 3.0).abs() < 1e-12);
+        assert!((t.variance(Class::Random) - 4.0).abs() < 1e-12);
+        // (2.5 - 4) / sqrt(5/12 + 4/3)
+        let expected = -1.5 / (5.0f64 / 12.0 + 4.0 / 3.0).sqrt();
+        assert!((t.t() - expected).abs() < 1e-12);
+
+        // Equal constant classes, no difference.
+        let mut t = WelchTTest::new();
+        for _ in 0..3 {
+            t.push(Class::Fixed, 7.0);
+            t.push(Class::Random, 7.0);
+        }
+        assert_eq!(t.t(), 0.0);
+
+        // Constant but different classes, the sign follows mean0 - mean1.
+        let mut t = WelchTTest::new();
+        for _ in 0..3 {
+            t.push(Class::Fixed, 7.0);
+            t.push(Class::Random, 9.0);
+        }
+        assert_eq!(t.t(), f64::NEG_INFINITY);
+        let mut t = WelchTTest::new();
+        for _ in 0..3 {
+            t.push(Class::Fixed, 9.0);
+            t.push(Class::Random, 7.0);
+        }
+        assert_eq!(t.t(), f64::INFINITY);
+    }
+
+    #[test]
+    fn dudect_analyze_test() {
+        // Well separated classes, one huge outlier in the random class.
+        let mut timings = vec![];
+        for i in 0..100 {
+            timings.push((Class::Fixed, 100.0 + (i % 5) as f64));
+            timings.push((Class::Random, 200.0 + (i % 7) as f64));
+        }
+        timings.push((Class::Random, 1e9));
+        let report = Dudect::analyze(&timings);
+        assert_eq!(report.fixed, 100);
+        assert_eq!(report.random, 101);
+        assert!(report.leak());
+        assert!(report.t < -dudect::THRESHOLD);
+
+        let same: Vec<(Class, f64)> = (0..200)
+            .map(|i| {
+                let class = if i % 2 == 0 {
+                    Class::Fixed
+                } else {
+                    Class::Random
+                };
+                (class, 100.0 + (i / 2 % 5) as f64)
+            })
+            .collect();
+        assert!(!Dudect::analyze(&same).leak());
+    }
+
+    #[test]
+    fn dudect_analyze_nan_test() {
+        // A NaN timing sorts last instead of panicking.
+        let mut timings: Vec<(Class, f64)> = (0..100)
+            .map(|i| (Class::Fixed, 100.0 + (i % 5) as f64))
+            .chain((0..100).map(|i| (Class::Random, 100.0 + (i % 5) as f64)))
+            .collect();
+        timings.push((Class::Random, f64::NAN));
+        let report = Dudect::analyze(&timings);
+        assert_eq!(report.fixed, 100);
+        assert_eq!(report.random, 100);
+        assert!(!report.leak());
+
+        assert_eq!(Dudect::analyze(&[]).t, 0.0);
+    }
+
+    #[test]
+    fn dudect_leaky_test() {
+        // An early-exit comparison leaks the length of the common prefix:
+        // the fixed class matches the secret, the random class does not.
+        let secret = vec![0x5au8; 4096];
+        let dudect = Dudect::new(2000, 7);
+        let report = dudect.run(
+            |class, rng| match class {
+                Class::Fixed => secret.clone(),
+                Class::Random => (0..4096).map(|_| rng.next_u64() as u8).collect(),
+            },
+            |input: &Vec<u8>| {
+                for (a, b) in input.iter().zip(secret.iter()) {
+                    if black_box(*a) != *b {
+                        return false;
+                    }
+                }
+                true
+            },
+        );
+        println!("early-exit compare: {}", report);
+        assert_eq!(report.fixed + report.random, 2000);
+        assert!(report.leak());
+        assert!(report.fixed_mean > report.random_mean);
+    }
+}
@@ -0,0 +1,408 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use algebra::bigint::BigUint;
+use algebra::Integer;
+
+use crate::field::Field;
+
+/// Element of a prime field, tied to its field by a borrow.
+///
+/// ```text
+/// The value is always reduced into [0, p).
+/// Mixing elements of different fields panics.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+///
+/// fn main() {
+///     let fp = field::Field::new(37);
+///     let a = fp.element(15);
+///     let b = fp.element(33);
+///     println!("{}", a * b + a / b - (-a));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FieldElement<'a, T> {
+    value: T,
+    field: &'a Field<T>,
+}
+
+impl<'a, T: Integer> FieldElement<'a, T> {
+    /// Creates the element value (mod p).
+    pub fn new(field: &'a Field<T>, value: T) -> Self {
+        FieldElement {
+            value: field.reduce(value),
+            field,
+        }
+    }
+
+    pub fn zero(field: &'a Field<T>) -> Self {
+        FieldElement::new(field, T::zero())
+    }
+
+    pub fn one(field: &'a Field<T>) -> Self {
+        FieldElement::new(field, T::one())
+    }
+
+    /// Returns the value in [0, p).
+    pub fn value(&self) -> T {
+        self.value.clone()
+    }
+
+    /// Returns the field of the element.
+    pub fn field(&self) -> &'a Field<T> {
+        self.field
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns the inverse, None for zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.element(14).inverse());
+    /// }
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        self.field.inv(self.value()).map(|v| self.with_value(v))
+    }
+
+    /// Returns self^exponent, a negative exponent inverts first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{}", fp.element(18).pow(11));
+    /// }
+    /// ```
+    pub fn pow(&self, exponent: T) -> Self {
+        self.with_value(self.field.exp(self.value(), exponent))
+    }
+
+    /// Returns a square root, None if the element is not a square.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(31);
+    ///     println!("{:?}", fp.element(28).sqrt().map(|x| x.value()));
+    /// }
+    /// ```
+    pub fn sqrt(&self) -> Option<Self> {
+        self.field.sqrt(self.value()).map(|v| self.with_value(v))
+    }
+
+    fn with_value(&self, value: T) -> Self {
+        FieldElement {
+            value,
+            field: self.field,
+        }
+    }
+
+    fn add_elements(&self, rhs: &Self) -> Self {
+        self.with_value(self.field.add(self.value(), rhs.value()))
+    }
+
+    fn sub_elements(&self, rhs: &Self) -> Self {
+        self.with_value(self.field.sub(self.value(), rhs.value()))
+    }
+
+    fn mul_elements(&self, rhs: &Self) -> Self {
+        self.with_value(self.field.mul(self.value(), rhs.value()))
+    }
+
+    fn div_elements(&self, rhs: &Self) -> Self {
+        self.mul_elements(&rhs.inverse().expect("division by zero"))
+    }
+
+    fn check(&self, other: &Self) {
+        assert!(
+            self.field.prime() == other.field.prime(),
+            "elements of different fields"
+        );
+    }
+}
+
+impl<'a, T: Integer> PartialEq for FieldElement<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.field.prime() == other.field.prime() && self.value == other.value
+    }
+}
+
+impl<'a, T: Integer> Eq for FieldElement<'a, T> {}
+
+impl<'a, T: Integer> fmt::Display for FieldElement<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<'a, T: Integer> Neg for FieldElement<'a, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.with_value(self.field.sub(T::zero(), self.value()))
+    }
+}
+
+impl<'a, T: Integer> Neg for &FieldElement<'a, T> {
+    type Output = FieldElement<'a, T>;
+
+    fn neg(self) -> FieldElement<'a, T> {
+        -self.clone()
+    }
+}
+
+/// Implements a binary operator for all owned/borrowed operand pairs.
+macro_rules! impl_op {
+    ($trait:ident, $method:ident, $op:ident) => {
+        impl<'a, 'b, T: Integer> $trait<&'b FieldElement<'a, T>> for &'b FieldElement<'a, T> {
+            type Output = FieldElement<'a, T>;
+
+            fn $method(self, rhs: &'b FieldElement<'a, T>) -> FieldElement<'a, T> {
+                self.check(rhs);
+                self.$op(rhs)
+            }
+        }
+
+        impl<'a, T: Integer> $trait for FieldElement<'a, T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                $trait::$method(&self, &rhs)
+            }
+        }
+
+        impl<'a, 'b, T: Integer> $trait<&'b FieldElement<'a, T>> for FieldElement<'a, T> {
+            type Output = Self;
+
+            fn $method(self, rhs: &'b FieldElement<'a, T>) -> Self {
+                $trait::$method(&self, rhs)
+            }
+        }
+
+        impl<'a, 'b, T: Integer> $trait<FieldElement<'a, T>> for &'b FieldElement<'a, T> {
+            type Output = FieldElement<'a, T>;
+
+            fn $method(self, rhs: FieldElement<'a, T>) -> FieldElement<'a, T> {
+                $trait::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, add_elements);
+impl_op!(Sub, sub, sub_elements);
+impl_op!(Mul, mul, mul_elements);
+impl_op!(Div, div, div_elements);
+
+/// Arithmetic of an extension field (Fp2, Fp6, Fp12), ExtensionElement operators call it.
+pub trait Extension {
+    type Element: Clone + PartialEq + fmt::Debug;
+
+    fn add(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    fn sub(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    fn mul(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    fn neg(&self, a: &Self::Element) -> Self::Element;
+
+    fn inv(&self, a: &Self::Element) -> Option<Self::Element>;
+
+    fn exp(&self, a: &Self::Element, e: &BigUint) -> Self::Element;
+
+    /// Returns true if other is built from the same prime and non-residues.
+    fn same(&self, other: &Self) -> bool;
+}
+
+/// Element of an extension field, tied to it by a borrow.
+///
+/// ```text
+/// The extension element counterpart of FieldElement, the tower keeps
+/// the method API, bind an element to get the operators.
+/// Mixing elements of different extensions panics.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::fp2;
+///
+/// fn main() {
+///     let fp2 = fp2::Fp2::new(field::Field::new(31), -1);
+///     let a = fp2.bind(fp2.element(3, 4));
+///     let b = fp2.bind(fp2.element(5, 6));
+///     println!("{}", &a * &b + &a / &b - (-a));
+/// }
+/// ```
+pub struct ExtensionElement<'a, E: Extension> {
+    value: E::Element,
+    extension: &'a E,
+}
+
+impl<'a, E: Extension> ExtensionElement<'a, E> {
+    pub fn new(extension: &'a E, value: E::Element) -> Self {
+        ExtensionElement { value, extension }
+    }
+
+    /// Returns the element of the tower, for its method API.
+    pub fn value(&self) -> E::Element {
+        self.value.clone()
+    }
+
+    /// Returns the extension of the element.
+    pub fn extension(&self) -> &'a E {
+        self.extension
+    }
+
+    /// Returns the inverse, None for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        self.extension.inv(&self.value).map(|v| self.with_value(v))
+    }
+
+    /// Returns self^exponent.
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        self.with_value(self.extension.exp(&self.value, exponent))
+    }
+
+    fn with_value(&self, value: E::Element) -> Self {
+        ExtensionElement {
+            value,
+            extension: self.extension,
+        }
+    }
+
+    fn add_elements(&self, rhs: &Self) -> Self {
+        self.with_value(self.extension.add(&self.value, &rhs.value))
+    }
+
+    fn sub_elements(&self, rhs: &Self) -> Self {
+        self.with_value(self.extension.sub(&self.value, &rhs.value))
+    }
+
+    fn mul_elements(&self, rhs: &Self) -> Self {
+        self.with_value(self.extension.mul(&self.value, &rhs.value))
+    }
+
+    fn div_elements(&self, rhs: &Self) -> Self {
+        self.mul_elements(&rhs.inverse().expect("division by zero"))
+    }
+
+    fn check(&self, other: &Self) {
+        assert!(
+            self.extension.same(other.extension),
+            "elements of different extensions"
+        );
+    }
+}
+
+impl<'a, E: Extension> Clone for ExtensionElement<'a, E> {
+    fn clone(&self) -> Self {
+        self.with_value(self.value())
+    }
+}
+
+impl<'a, E: Extension> fmt::Debug for ExtensionElement<'a, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.value)
+    }
+}
+
+impl<'a, E: Extension> PartialEq for ExtensionElement<'a, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.extension.same(other.extension) && self.value == other.value
+    }
+}
+
+impl<'a, E: Extension> fmt::Display for ExtensionElement<'a, E>
+where
+    E::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<'a, E: Extension> Neg for ExtensionElement<'a, E> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl<'a, E: Extension> Neg for &ExtensionElement<'a, E> {
+    type Output = ExtensionElement<'a, E>;
+
+    fn neg(self) -> ExtensionElement<'a, E> {
+        self.with_value(self.extension.neg(&self.value))
+    }
+}
+
+/// impl_op for ExtensionElement.
+macro_rules! impl_extension_op {
+    ($trait:ident, $method:ident, $op:ident) => {
+        impl<'a, 'b, E: Extension> $trait<&'b ExtensionElement<'a, E>>
+            for &'b ExtensionElement<'a, E>
+        {
+            type Output = ExtensionElement<'a, E>;
+
+            fn $method(self, rhs: &'b ExtensionElement<'a, E>) -> ExtensionElement<'a, E> {
+                self.check(rhs);
+                self.$op(rhs)
+            }
+        }
+
+        impl<'a, E: Extension> $trait for ExtensionElement<'a, E> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                $trait::$method(&self, &rhs)
+            }
+        }
+
+        impl<'a, 'b, E: Extension> $trait<&'b ExtensionElement<'a, E>> for ExtensionElement<'a, E> {
+            type Output = Self;
+
+            fn $method(self, rhs: &'b ExtensionElement<'a, E>) -> Self {
+                $trait::$method(&self, rhs)
+            }
+        }
+
+        impl<'a, 'b, E: Extension> $trait<ExtensionElement<'a, E>> for &'b ExtensionElement<'a, E> {
+            type Output = ExtensionElement<'a, E>;
+
+            fn $method(self, rhs: ExtensionElement<'a, E>) -> ExtensionElement<'a, E> {
+                $trait::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+impl_extension_op!(Add, add, add_elements);
+impl_extension_op!(Sub, sub, sub_elements);
+impl_extension_op!(Mul, mul, mul_elements);
+impl_extension_op!(Div, div, div_elements);
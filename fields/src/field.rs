@@ -1,17 +1,189 @@
 // Copyright (c) BohuTANG
 // Code is licensed with BSD
 
-use algebra::arith;
+use std::rc::Rc;
+
+use algebra::barrett::BarrettReducer;
+use algebra::bigint::{BigInt, BigUint};
+use algebra::montgomery::MontgomeryContext;
+use algebra::symbol::{self, Symbol};
+use algebra::{arith, ct, factor, gcd, primality, Integer};
+
+use crate::element::FieldElement;
+
+/// Modular reduction strategy of a Field.
+///
+/// ```text
+/// Montgomery and Barrett build their context once in Field::with_reduction,
+/// Field::mul and Field::exp reduce through it, the clones of a field share it.
+/// add and sub need no division, they stay plain.
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Reduction {
+    /// Division based, algebra::arith.
+    Plain,
+    /// algebra::montgomery, the prime must be odd.
+    Montgomery,
+    /// algebra::barrett.
+    Barrett,
+    /// algebra::ct, constant time on u64 words, the prime must be below 2^63
+    /// and every value of the element type must fit in i64.
+    /// add, sub, mul, exp and inv run without operand dependent branches.
+    ConstantTime,
+}
 
 /// Field with prime.
-#[derive(Debug, Copy, Clone)]
-pub struct Field {
-    primer: i8,
+///
+/// The element type T can be any algebra::Integer,
+/// pick the width that fits the prime.
+#[derive(Debug, Clone)]
+pub struct Field<T> {
+    primer: T,
+    reducer: Reducer,
+}
+
+/// Precomputed context of the reduction strategy.
+#[derive(Debug, Clone)]
+enum Reducer {
+    Plain,
+    Montgomery(Rc<MontgomeryContext>),
+    Barrett(Rc<BarrettReducer>),
+    ConstantTime,
 }
 
-impl Field {
-    pub fn new(p: i8) -> Self {
-        Field { primer: p }
+impl<T: Integer> Field<T> {
+    pub fn new(p: T) -> Self {
+        Field::with_reduction(p, Reduction::Plain)
+    }
+
+    /// Creates a field, returns None if p is not a prime.
+    ///
+    /// ```text
+    /// Primality is decided by algebra::primality::is_prime (Baillie-PSW).
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     assert!(field::Field::new_checked(37).is_some());
+    ///     assert!(field::Field::new_checked(35).is_none());
+    /// }
+    /// ```
+    pub fn new_checked(p: T) -> Option<Self> {
+        if primality::is_prime(p.clone()) {
+            Some(Field::new(p))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a field which reduces products with the given strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::with_reduction(37, field::Reduction::Barrett);
+    ///     println!("{:?}", fp.mul(15,33));
+    /// }
+    /// ```
+    pub fn with_reduction(p: T, reduction: Reduction) -> Self {
+        let reducer = match reduction {
+            Reduction::Plain => Reducer::Plain,
+            Reduction::Montgomery => {
+                assert!(p.is_odd() && !p.is_one(), "Montgomery modulus must be odd");
+                Reducer::Montgomery(Rc::new(MontgomeryContext::new(&Self::to_biguint(&p))))
+            }
+            Reduction::Barrett => {
+                assert!(!p.is_zero(), "Barrett modulus must not be zero");
+                Reducer::Barrett(Rc::new(BarrettReducer::new(&Self::to_biguint(&p))))
+            }
+            Reduction::ConstantTime => {
+                assert!(
+                    p.to_i64().is_some() && p > T::one(),
+                    "constant-time backend needs 1 < p < 2^63"
+                );
+                let (above, below) = (i64::MAX as i128 + 1, i64::MIN as i128 - 1);
+                assert!(
+                    T::from_bigint(&BigInt::from(above)).is_none()
+                        && T::from_bigint(&BigInt::from(below)).is_none(),
+                    "constant-time backend needs an element type within i64"
+                );
+                Reducer::ConstantTime
+            }
+        };
+        Field { primer: p, reducer }
+    }
+
+    /// Returns the reduction strategy of the field.
+    pub fn reduction(&self) -> Reduction {
+        match self.reducer {
+            Reducer::Plain => Reduction::Plain,
+            Reducer::Montgomery(_) => Reduction::Montgomery,
+            Reducer::Barrett(_) => Reduction::Barrett,
+            Reducer::ConstantTime => Reduction::ConstantTime,
+        }
+    }
+
+    /// Returns the Montgomery context of a Reduction::Montgomery field.
+    pub fn montgomery(&self) -> Option<&MontgomeryContext> {
+        match &self.reducer {
+            Reducer::Montgomery(ctx) => Some(ctx),
+            _ => None,
+        }
+    }
+
+    /// Returns the Barrett reducer of a Reduction::Barrett field.
+    pub fn barrett(&self) -> Option<&BarrettReducer> {
+        match &self.reducer {
+            Reducer::Barrett(br) => Some(br),
+            _ => None,
+        }
+    }
+
+    /// Switches the field to another reduction strategy at runtime,
+    /// for example between a fast one and Reduction::ConstantTime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let mut fp = field::Field::new(37);
+    ///     fp.set_reduction(field::Reduction::ConstantTime);
+    ///     println!("{:?}", fp.mul(15,33));
+    /// }
+    /// ```
+    pub fn set_reduction(&mut self, reduction: Reduction) {
+        *self = Field::with_reduction(self.prime(), reduction);
+    }
+
+    /// Returns the prime of the field.
+    pub fn prime(&self) -> T {
+        self.primer.clone()
+    }
+
+    /// Returns a (mod primer) as an element of the field, with operators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     let x = fp.element(15) * fp.element(33);
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    pub fn element(&self, a: T) -> FieldElement<'_, T> {
+        FieldElement::new(self, a)
     }
 
     /// Computes two numbers sum in finite field.
@@ -30,8 +202,13 @@ impl Field {
     ///     println!("{:?}", fp.add(15,33));
     /// }
     /// ```
-    pub fn add(self, a: i8, b: i8) -> i8 {
-        arith::mod_add(a, b, self.primer)
+    pub fn add(&self, a: T, b: T) -> T {
+        match self.reduction() {
+            Reduction::ConstantTime => {
+                Self::from_word(ct::mod_add(self.word(a), self.word(b), self.word_prime()))
+            }
+            _ => arith::mod_add(a, b, self.prime()),
+        }
     }
 
     /// Computes two numbers sum in finite field.
@@ -50,8 +227,13 @@ impl Field {
     ///     println!("{:?}", fp.sub(15,33));
     /// }
     /// ```
-    pub fn sub(self, a: i8, b: i8) -> i8 {
-        arith::mod_sub(a, b, self.primer)
+    pub fn sub(&self, a: T, b: T) -> T {
+        match self.reduction() {
+            Reduction::ConstantTime => {
+                Self::from_word(ct::mod_sub(self.word(a), self.word(b), self.word_prime()))
+            }
+            _ => arith::mod_sub(a, b, self.prime()),
+        }
     }
 
     /// Computes two numbers product in finite field.
@@ -70,8 +252,148 @@ impl Field {
     ///     println!("{:?}", fp.mul(15,33));
     /// }
     /// ```
-    pub fn mul(self, a: i8, b: i8) -> i8 {
-        arith::mod_mul(a, b, self.primer)
+    pub fn mul(&self, a: T, b: T) -> T {
+        match &self.reducer {
+            Reducer::Plain => arith::mod_mul(a, b, self.prime()),
+            // mont_mul(a, b) = a * b * R^-1, one more by R^2 cancels R^-1.
+            Reducer::Montgomery(ctx) => {
+                let ab = ctx.mont_mul(&self.lift(a), &self.lift(b));
+                Self::lower(&ctx.mont_mul(&ab, ctx.r2()))
+            }
+            Reducer::Barrett(br) => Self::lower(&br.mul(&self.lift(a), &self.lift(b))),
+            Reducer::ConstantTime => {
+                Self::from_word(ct::mod_mul(self.word(a), self.word(b), self.word_prime()))
+            }
+        }
+    }
+
+    /// Computes the inverse with the extended Euclidean algorithm.
+    ///
+    /// ```text
+    /// a * a^-1 = 1 (mod primer)
+    /// Returns None if a = 0 (mod primer).
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.inv(14));
+    /// }
+    /// ```
+    pub fn inv(&self, a: T) -> Option<T> {
+        if self.reduction() == Reduction::ConstantTime {
+            return self.inv_fermat(a);
+        }
+        let a = arith::reduce(a, self.prime());
+        if a.is_zero() {
+            return None;
+        }
+        Some(arith::mod_inv(a, self.prime()))
+    }
+
+    /// Computes the inverse with Fermat's little theorem.
+    ///
+    /// ```text
+    /// a^(p-1) = 1 (mod p), so a^-1 = a^(p-2) (mod p)
+    /// Returns None if a = 0 (mod primer).
+    /// One exponentiation with the field's reduction, no divisions.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.inv_fermat(14));
+    /// }
+    /// ```
+    pub fn inv_fermat(&self, a: T) -> Option<T> {
+        let a = self.reduce(a);
+        // Zero is exponentiated as well, only the result is dropped.
+        let inv = self.exp(a.clone(), self.prime() - T::two());
+        let zero = if self.reduction() == Reduction::ConstantTime {
+            ct::eq(self.word(a), 0) == 1
+        } else {
+            a.is_zero()
+        };
+        if zero {
+            None
+        } else {
+            Some(inv)
+        }
+    }
+
+    /// Computes two numbers division in finite field.
+    ///
+    /// ```text
+    /// a / b = a * b^-1 (mod primer)
+    /// Returns None if b = 0 (mod primer).
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.div(4, 14));
+    /// }
+    /// ```
+    pub fn div(&self, a: T, b: T) -> Option<T> {
+        Some(self.mul(a, self.inv(b)?))
+    }
+
+    /// Inverts all the elements with one inversion, Montgomery's trick.
+    ///
+    /// ```text
+    /// Returns None if any element is 0 (mod primer).
+    /// ```
+    ///
+    /// ```text
+    /// prefix products: c[i] = a[0] * a[1] * ... * a[i]
+    /// t = c[n-1]^-1
+    /// for i = n-1 down to 1:
+    ///     a[i]^-1 = t * c[i-1]
+    ///     t = t * a[i]
+    /// a[0]^-1 = t
+    /// 1 inversion and 3(n - 1) multiplications.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.batch_inv(&[2, 3, 14]));
+    /// }
+    /// ```
+    pub fn batch_inv(&self, a: &[T]) -> Option<Vec<T>> {
+        let mut prefix = Vec::with_capacity(a.len());
+        let mut c = T::one();
+        for x in a.iter() {
+            c = self.mul(c, x.clone());
+            prefix.push(c.clone());
+        }
+
+        let mut t = self.inv(c)?;
+        let mut res = vec![T::zero(); a.len()];
+        for i in (1..a.len()).rev() {
+            res[i] = self.mul(t.clone(), prefix[i - 1].clone());
+            t = self.mul(t, a[i].clone());
+        }
+        if !a.is_empty() {
+            res[0] = t;
+        }
+        Some(res)
     }
 
     /// Computes exponention in finite field.
@@ -90,8 +412,28 @@ impl Field {
     ///     println!("{:?}", fp.exp(15,33));
     /// }
     /// ```
-    pub fn exp(self, base: i8, exponent: i8) -> i8 {
-        arith::mod_exp(base, exponent, self.primer)
+    pub fn exp(&self, base: T, exponent: T) -> T {
+        match self.reducer {
+            Reducer::Plain => return arith::mod_exp(base, exponent, self.prime()),
+            Reducer::ConstantTime => return Self::from_word(self.exp_word(base, exponent)),
+            _ => {}
+        }
+
+        // a^-e = (a^-1)^e, the magnitude of a BigInt never overflows.
+        let e = exponent.to_bigint();
+        let base = if e.is_negative() {
+            arith::mod_inv(base, self.prime())
+        } else {
+            base
+        };
+        let (b, e) = (self.lift(base), e.magnitude().clone());
+        match &self.reducer {
+            Reducer::Montgomery(ctx) => {
+                Self::lower(&ctx.from_mont(&ctx.mont_exp(&ctx.to_mont(&b), &e)))
+            }
+            Reducer::Barrett(br) => Self::lower(&br.exp(&b, &e)),
+            _ => unreachable!(),
+        }
     }
 
     /// Computes the square root (mod primer) of 'a'.
@@ -104,7 +446,10 @@ impl Field {
     /// Solve the congruence of the form:
     /// x^2 = a (mod p)
     /// Note that p - x is also a root.
-    /// Here, we only handle the case that primer%4 ==3.
+    /// p = 2:           x = a
+    /// p = 3 (mod 4):   x = a ^ ((p + 1) / 4)
+    /// p = 5 (mod 8):   Atkin
+    /// p = 1 (mod 8):   Tonelli-Shanks
     /// ```
     ///
     /// # Examples
@@ -117,27 +462,191 @@ impl Field {
     ///     println!("{:?}", fp.sqrt(28));
     /// }
     /// ```
-    pub fn sqrt(self, a: i8) -> Option<i8> {
-        let a = a % self.primer;
-        if a == 0 {
-            return Some(0);
-        } else if self.primer == 2 {
-            return Some(self.primer);
-        } else if self.primer % 4 != 3 {
+    pub fn sqrt(&self, a: T) -> Option<T> {
+        let p = self.prime();
+        let a = arith::reduce(a, p.clone());
+        if a.is_zero() || p == T::two() {
+            return Some(a);
+        }
+        if self.legendre_symbol(a.clone()) != Symbol::One {
             return None;
         }
 
-        let ls = self.legendre_symbol(a);
-        match ls {
-            1 => Some(arith::mod_exp(a, (self.primer + 1) / 4, self.primer)),
-            _ => None,
+        if p.bit(1) {
+            // (p + 1) / 4 without overflowing p + 1.
+            Some(self.exp(a, (p >> 2) + T::one()))
+        } else if p.bit(2) {
+            Some(self.sqrt_atkin(a))
+        } else {
+            self.sqrt_tonelli_shanks(a)
+        }
+    }
+
+    /// Computes the square root with Atkin's formula, p = 5 (mod 8).
+    ///
+    /// ```text
+    /// b = (2a) ^ ((p - 5) / 8)
+    /// i = 2a * b^2, i^2 = -1
+    /// x = a * b * (i - 1)
+    /// ```
+    fn sqrt_atkin(&self, a: T) -> T {
+        let a2 = self.add(a.clone(), a.clone());
+        let b = self.exp(a2.clone(), self.prime() >> 3);
+        let i = self.mul(a2, self.mul(b.clone(), b.clone()));
+        self.mul(self.mul(a, b), self.sub(i, T::one()))
+    }
+
+    /// Computes the square root with Tonelli-Shanks, for any prime, a itself for p = 2.
+    ///
+    /// ```text
+    /// Returns None if has no square root.
+    /// ```
+    ///
+    /// ```text
+    /// Algorithm:
+    /// p - 1 = q * 2^s, q odd
+    /// z: a quadratic non-residue
+    /// m = s, c = z^q, t = a^q, r = a^((q + 1) / 2)
+    /// loop:
+    ///     if t = 1: return r
+    ///     find the least i, 0 < i < m, t^(2^i) = 1
+    ///     b = c^(2^(m - i - 1))
+    ///     m = i, c = b^2, t = t * b^2, r = r * b
+    /// r^2 = a * t is kept, the order of t halves every round.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(41);
+    ///     println!("{:?}", fp.sqrt_tonelli_shanks(10));
+    /// }
+    /// ```
+    pub fn sqrt_tonelli_shanks(&self, a: T) -> Option<T> {
+        let p = self.prime();
+        let a = arith::reduce(a, p.clone());
+        if a.is_zero() || p == T::two() {
+            return Some(a);
+        }
+        if self.legendre_symbol(a.clone()) != Symbol::One {
+            return None;
+        }
+
+        let p1 = p - T::one();
+        let s = (0..p1.bits()).find(|&i| p1.bit(i)).unwrap();
+        let q = p1 >> s;
+        let z = self.non_residue();
+
+        let mut m = s;
+        let mut c = self.exp(z, q.clone());
+        let mut t = self.exp(a.clone(), q.clone());
+        let mut r = self.exp(a, (q >> 1) + T::one());
+        while !t.is_one() {
+            let mut i = 0;
+            let mut t2i = t.clone();
+            while !t2i.is_one() {
+                t2i = self.mul(t2i.clone(), t2i);
+                i += 1;
+            }
+
+            let mut b = c;
+            for _ in 0..(m - i - 1) {
+                b = self.mul(b.clone(), b);
+            }
+            m = i;
+            c = self.mul(b.clone(), b.clone());
+            t = self.mul(t, c.clone());
+            r = self.mul(r, b);
+        }
+        Some(r)
+    }
+
+    /// Computes the square root with Cipolla's algorithm, for any prime, a itself for p = 2.
+    ///
+    /// ```text
+    /// Returns None if has no square root.
+    /// ```
+    ///
+    /// ```text
+    /// Algorithm:
+    /// find t with w = t^2 - a a quadratic non-residue
+    /// work in Fp2 = Fp(sqrt(w)):
+    /// (x1 + y1 * sqrt(w)) * (x2 + y2 * sqrt(w))
+    ///     = (x1 * x2 + y1 * y2 * w) + (x1 * y2 + x2 * y1) * sqrt(w)
+    /// x = (t + sqrt(w)) ^ ((p + 1) / 2), which lies in Fp.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(41);
+    ///     println!("{:?}", fp.sqrt_cipolla(10));
+    /// }
+    /// ```
+    pub fn sqrt_cipolla(&self, a: T) -> Option<T> {
+        let p = self.prime();
+        let a = arith::reduce(a, p.clone());
+        if a.is_zero() || p == T::two() {
+            return Some(a);
+        }
+        if self.legendre_symbol(a.clone()) != Symbol::One {
+            return None;
+        }
+
+        let mut t = T::zero();
+        let w = loop {
+            t = t + T::one();
+            let w = self.sub(self.mul(t.clone(), t.clone()), a.clone());
+            if self.legendre_symbol(w.clone()) == Symbol::MinusOne {
+                break w;
+            }
+        };
+
+        let mul = |(x1, y1): (T, T), (x2, y2): (T, T)| {
+            let x = self.add(
+                self.mul(x1.clone(), x2.clone()),
+                self.mul(self.mul(y1.clone(), y2.clone()), w.clone()),
+            );
+            let y = self.add(self.mul(x1, y2), self.mul(x2, y1));
+            (x, y)
+        };
+
+        // (p + 1) / 2 without overflowing p + 1.
+        let e = (p >> 1) + T::one();
+        let base = (t, T::one());
+        let mut res = (T::one(), T::zero());
+        for i in (0..e.bits()).rev() {
+            res = mul(res.clone(), res);
+            if e.bit(i) {
+                res = mul(res, base.clone());
+            }
         }
+        Some(res.0)
     }
 
-    /// Computes the Legendre symbol a|p using Euler's criterion.
+    /// Returns the least quadratic non-residue, p must be an odd prime.
+    fn non_residue(&self) -> T {
+        assert!(self.prime() != T::two(), "every unit is a square modulo 2");
+        let mut z = T::two();
+        while self.legendre_symbol(z.clone()) != Symbol::MinusOne {
+            z = z + T::one();
+        }
+        z
+    }
+
+    /// Computes the Legendre symbol a|p.
     ///
     /// ```text
-    /// Returns 1 if a has a square root modulo p, -1 otherwise.
+    /// Returns One if a has a square root modulo p, MinusOne otherwise,
+    /// Zero if a = 0 (mod p).
+    /// Computed by algebra::symbol::jacobi with quadratic reciprocity,
+    /// every odd a is a square modulo 2.
     /// ```
     ///
     /// # Examples
@@ -150,13 +659,204 @@ impl Field {
     ///     println!("{:?}", fp.legendre_symbol(28));
     /// }
     /// ```
-    pub fn legendre_symbol(self, a: i8) -> i8 {
-        let p1 = self.primer - 1;
-        let ls = arith::mod_exp(a, p1 >> 1, self.primer);
-        if ls == p1 {
-            -1
-        } else {
-            ls
+    pub fn legendre_symbol(&self, a: T) -> Symbol {
+        let p = self.prime();
+        if p == T::two() {
+            return if a.is_even() {
+                Symbol::Zero
+            } else {
+                Symbol::One
+            };
         }
+        symbol::jacobi(a, p)
+    }
+
+    /// Computes the multiplicative order of a, the least n > 0 with a^n = 1.
+    ///
+    /// ```text
+    /// Returns None if a = 0 (mod p).
+    /// n divides p - 1: start from p - 1, divide out every prime q of p - 1
+    /// while a^(n / q) = 1 still holds.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.multiplicative_order(10));
+    /// }
+    /// ```
+    pub fn multiplicative_order(&self, a: T) -> Option<T> {
+        let p = self.prime();
+        let a = arith::reduce(a, p.clone());
+        if a.is_zero() {
+            return None;
+        }
+        let mut n = p.clone() - T::one();
+        for (q, _) in factor::factorize(n.clone()) {
+            while (n.clone() % q.clone()).is_zero()
+                && arith::mod_exp(a.clone(), n.clone() / q.clone(), p.clone()).is_one()
+            {
+                n = n / q.clone();
+            }
+        }
+        Some(n)
+    }
+
+    /// Returns the least primitive root, a generator of the multiplicative group.
+    ///
+    /// ```text
+    /// g generates F_p^* iff g^((p - 1) / q) != 1 for every prime q | p - 1.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.primitive_root());
+    /// }
+    /// ```
+    pub fn primitive_root(&self) -> T {
+        let p = self.prime();
+        let n = p.clone() - T::one();
+        let factors = factor::factorize(n.clone());
+        let mut g = T::one();
+        while factors
+            .iter()
+            .any(|(q, _)| arith::mod_exp(g.clone(), n.clone() / q.clone(), p.clone()).is_one())
+        {
+            g = g + T::one();
+        }
+        g
+    }
+
+    /// Returns the k-th roots of unity, all x with x^k = 1, sorted.
+    ///
+    /// ```text
+    /// They form the cyclic subgroup of order d = gcd(k, p - 1),
+    /// generated by g^((p - 1) / d), g a primitive root.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.roots_of_unity(4));
+    /// }
+    /// ```
+    pub fn roots_of_unity(&self, k: T) -> Vec<T> {
+        assert!(!k.is_zero() && !k.is_negative(), "k must be positive");
+        let p = self.prime();
+        let n = p.clone() - T::one();
+        let d = gcd::gcd(k, n.clone());
+        let w = arith::mod_exp(self.primitive_root(), n / d.clone(), p);
+
+        let mut roots = vec![T::one()];
+        let mut x = w.clone();
+        while !x.is_one() {
+            roots.push(x.clone());
+            x = self.mul(x, w.clone());
+        }
+        roots.sort();
+        roots
+    }
+
+    /// Returns true if a is a k-th power residue, x^k = a has a solution.
+    ///
+    /// ```text
+    /// a = 0: always, x = 0.
+    /// Otherwise, with d = gcd(k, p - 1), iff a^((p - 1) / d) = 1.
+    /// k = 2 is the Legendre symbol test.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     println!("{:?}", fp.is_power_residue(8, 3));
+    /// }
+    /// ```
+    pub fn is_power_residue(&self, a: T, k: T) -> bool {
+        assert!(!k.is_zero() && !k.is_negative(), "k must be positive");
+        let p = self.prime();
+        let a = arith::reduce(a, p.clone());
+        if a.is_zero() {
+            return true;
+        }
+        let n = p.clone() - T::one();
+        let d = gcd::gcd(k, n.clone());
+        arith::mod_exp(a, n / d, p).is_one()
+    }
+
+    /// Reduces a into [0, p) and lifts it to the reducers' representation.
+    fn lift(&self, a: T) -> BigUint {
+        Self::to_biguint(&arith::reduce(a, self.prime()))
+    }
+
+    fn to_biguint(a: &T) -> BigUint {
+        a.to_bigint().to_biguint().unwrap()
+    }
+
+    fn lower(a: &BigUint) -> T {
+        T::from_bigint(&a.clone().into()).unwrap()
+    }
+
+    /// Reduces a into [0, p), with ct::reduce under Reduction::ConstantTime.
+    pub(crate) fn reduce(&self, a: T) -> T {
+        if self.reduction() == Reduction::ConstantTime {
+            return Self::from_word(self.word(a));
+        }
+        arith::reduce(a, self.prime())
+    }
+
+    /// Reduces a into [0, p) as a word of the constant-time backend.
+    ///
+    /// ```text
+    /// a < 0: p - (|a| mod p), both signs are computed and one is selected.
+    /// ```
+    fn word(&self, a: T) -> u64 {
+        let p = self.word_prime();
+        let a = a.to_i64().unwrap() as u64;
+        let neg = a >> 63;
+        let r = ct::reduce(ct::select(neg, a.wrapping_neg(), a), p);
+        ct::select(neg, ct::mod_sub(0, r, p), r)
+    }
+
+    /// Computes base^exponent on words, a negative exponent by Fermat.
+    ///
+    /// ```text
+    /// n = p - 1, a^n = 1 for a != 0, so a^-e = a^(n - (e mod n))
+    /// Both signs are computed and one is selected.
+    /// Zero has no inverse, a negative exponent panics on it like arith::mod_inv.
+    /// ```
+    fn exp_word(&self, base: T, exponent: T) -> u64 {
+        let p = self.word_prime();
+        let n = p - 1;
+        let e = exponent.to_i64().unwrap() as u64;
+        let neg = e >> 63;
+        let a = self.word(base);
+        assert!(neg & ct::eq(a, 0) == 0, "zero has no inverse");
+        let e = ct::select(neg, n - ct::reduce(e.wrapping_neg(), n), e);
+        ct::mod_exp(a, e, p)
+    }
+
+    fn word_prime(&self) -> u64 {
+        self.primer.to_i64().unwrap() as u64
+    }
+
+    fn from_word(a: u64) -> T {
+        T::from_i64(a as i64).unwrap()
     }
 }
@@ -0,0 +1,349 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::bigint::BigUint;
+use algebra::Integer;
+
+use crate::element::{Extension, ExtensionElement};
+use crate::fp2::Fp2Element;
+use crate::fp6::{Fp6, Fp6Element};
+
+/// Element c0 + c1 * w of Fp12, w^2 = v.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fp12Element<T> {
+    pub c0: Fp6Element<T>,
+    pub c1: Fp6Element<T>,
+}
+
+/// Quadratic extension Fp12 = Fp6[w] / (w^2 - v), the tower Fp2 -> Fp6 -> Fp12.
+///
+/// ```text
+/// w^6 = v^3 = xi, so w^2 - v is irreducible iff xi is not a square in Fp2.
+/// The target group of a pairing on a curve with embedding degree 12
+/// (BN, BLS12) lives in Fp12, reached by the final exponentiation:
+///     f^((p^12 - 1) / r) = (f^((p^6 - 1) * (p^2 + 1)))^((p^4 - p^2 + 1) / r)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::fp12;
+/// use fields::fp2;
+/// use fields::fp6;
+///
+/// fn main() {
+///     let fp2 = fp2::Fp2::new(field::Field::new(103), -1);
+///     let fp12 = fp12::Fp12::new(fp6::Fp6::with_default_non_residue(fp2));
+///     let w = fp12.element(fp12.fp6().zero(), fp12.fp6().one());
+///     println!("{:?}", fp12.square(&w));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fp12<T> {
+    fp6: Fp6<T>,
+    // frobenius[i] = xi^((p^i - 1) / 6), i < 12
+    frobenius: Vec<Fp2Element<T>>,
+}
+
+impl<T: Integer> Fp12<T> {
+    /// Creates Fp12 over fp6 with w^2 = v.
+    ///
+    /// ```text
+    /// Panics if xi, v^3 = xi, is a square in Fp2.
+    /// ```
+    pub fn new(fp6: Fp6<T>) -> Self {
+        let fp2 = fp6.fp2();
+        let xi = fp6.non_residue();
+        assert!(!fp2.is_square(&xi), "w^2 - v must be irreducible");
+
+        let p = fp2.field().prime().to_bigint().to_biguint().unwrap();
+        let six = BigUint::from(6u32);
+        let frobenius = (0..12)
+            .map(|i| fp2.exp(&xi, &((p.pow(i) - BigUint::one()) / &six)))
+            .collect();
+        Fp12 { fp6, frobenius }
+    }
+
+    /// Returns the base field Fp6.
+    pub fn fp6(&self) -> &Fp6<T> {
+        &self.fp6
+    }
+
+    pub fn element(&self, c0: Fp6Element<T>, c1: Fp6Element<T>) -> Fp12Element<T> {
+        Fp12Element { c0, c1 }
+    }
+
+    pub fn zero(&self) -> Fp12Element<T> {
+        self.element(self.fp6.zero(), self.fp6.zero())
+    }
+
+    pub fn one(&self) -> Fp12Element<T> {
+        self.element(self.fp6.one(), self.fp6.zero())
+    }
+
+    pub fn is_zero(&self, a: &Fp12Element<T>) -> bool {
+        self.fp6.is_zero(&a.c0) && self.fp6.is_zero(&a.c1)
+    }
+
+    /// Binds a to the extension, with operators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::fp12;
+    /// use fields::fp2;
+    /// use fields::fp6;
+    ///
+    /// fn main() {
+    ///     let fp2 = fp2::Fp2::new(field::Field::new(103), -1);
+    ///     let fp12 = fp12::Fp12::new(fp6::Fp6::with_default_non_residue(fp2));
+    ///     let w = fp12.bind(fp12.element(fp12.fp6().zero(), fp12.fp6().one()));
+    ///     println!("{:?}", &w * &w - w);
+    /// }
+    /// ```
+    pub fn bind(&self, a: Fp12Element<T>) -> ExtensionElement<'_, Fp12<T>> {
+        ExtensionElement::new(self, a)
+    }
+
+    pub fn add(&self, a: &Fp12Element<T>, b: &Fp12Element<T>) -> Fp12Element<T> {
+        self.element(self.fp6.add(&a.c0, &b.c0), self.fp6.add(&a.c1, &b.c1))
+    }
+
+    pub fn sub(&self, a: &Fp12Element<T>, b: &Fp12Element<T>) -> Fp12Element<T> {
+        self.element(self.fp6.sub(&a.c0, &b.c0), self.fp6.sub(&a.c1, &b.c1))
+    }
+
+    pub fn neg(&self, a: &Fp12Element<T>) -> Fp12Element<T> {
+        self.sub(&self.zero(), a)
+    }
+
+    /// Computes the product with Karatsuba, 3 Fp6 multiplications.
+    ///
+    /// ```text
+    /// v0 = a0 * b0, v1 = a1 * b1
+    /// c0 = v0 + v * v1
+    /// c1 = (a0 + a1) * (b0 + b1) - v0 - v1
+    /// ```
+    pub fn mul(&self, a: &Fp12Element<T>, b: &Fp12Element<T>) -> Fp12Element<T> {
+        let f = &self.fp6;
+        let v0 = f.mul(&a.c0, &b.c0);
+        let v1 = f.mul(&a.c1, &b.c1);
+        let t = f.mul(&f.add(&a.c0, &a.c1), &f.add(&b.c0, &b.c1));
+        self.element(f.add(&v0, &f.mul_by_v(&v1)), f.sub(&f.sub(&t, &v0), &v1))
+    }
+
+    /// Computes the square, complex squaring with 2 Fp6 multiplications.
+    ///
+    /// ```text
+    /// t = a0 * a1
+    /// c0 = (a0 + a1) * (a0 + v * a1) - t - v * t
+    /// c1 = 2 * t
+    /// ```
+    pub fn square(&self, a: &Fp12Element<T>) -> Fp12Element<T> {
+        let f = &self.fp6;
+        let t = f.mul(&a.c0, &a.c1);
+        let s = f.mul(&f.add(&a.c0, &a.c1), &f.add(&a.c0, &f.mul_by_v(&a.c1)));
+        self.element(f.sub(&f.sub(&s, &t), &f.mul_by_v(&t)), f.add(&t, &t))
+    }
+
+    /// Returns the conjugate c0 - c1 * w, which equals a^(p^6).
+    /// On the cyclotomic subgroup it is the inverse.
+    pub fn conjugate(&self, a: &Fp12Element<T>) -> Fp12Element<T> {
+        self.element(a.c0.clone(), self.fp6.neg(&a.c1))
+    }
+
+    /// Computes the inverse, None for zero.
+    ///
+    /// ```text
+    /// a^-1 = (a0 - a1 * w) / (a0^2 - v * a1^2)
+    /// ```
+    pub fn inv(&self, a: &Fp12Element<T>) -> Option<Fp12Element<T>> {
+        let f = &self.fp6;
+        let n = f.sub(&f.square(&a.c0), &f.mul_by_v(&f.square(&a.c1)));
+        let n = f.inv(&n)?;
+        Some(self.element(f.mul(&a.c0, &n), f.neg(&f.mul(&a.c1, &n))))
+    }
+
+    /// Computes a / b, None if b is zero.
+    pub fn div(&self, a: &Fp12Element<T>, b: &Fp12Element<T>) -> Option<Fp12Element<T>> {
+        Some(self.mul(a, &self.inv(b)?))
+    }
+
+    /// Computes a^e by square-and-multiply.
+    pub fn exp(&self, a: &Fp12Element<T>, e: &BigUint) -> Fp12Element<T> {
+        let mut res = self.one();
+        for i in (0..e.bits()).rev() {
+            res = self.square(&res);
+            if e.bit(i) {
+                res = self.mul(&res, a);
+            }
+        }
+        res
+    }
+
+    /// Applies the Frobenius map a -> a^(p^power).
+    ///
+    /// ```text
+    /// w^(p^i) = xi^((p^i - 1) / 6) * w, so
+    /// (a0 + a1 * w)^(p^i) = a0^(p^i) + a1^(p^i) * xi^((p^i - 1) / 6) * w
+    /// ```
+    pub fn frobenius(&self, a: &Fp12Element<T>, power: usize) -> Fp12Element<T> {
+        let f = &self.fp6;
+        self.element(
+            f.frobenius(&a.c0, power),
+            f.mul_fp2(&f.frobenius(&a.c1, power), &self.frobenius[power % 12]),
+        )
+    }
+
+    /// Squares an element of the cyclotomic subgroup, a^(p^4 - p^2 + 1) = 1,
+    /// Granger-Scott with 6 Fp2 multiplications, Fp12 seen as Fp4^3.
+    ///
+    /// ```text
+    /// a = (z0 + z1 * w^3) + (z2 + z3 * w^3) * w + (z4 + z5 * w^3) * w^2
+    /// (t0, t1) = (z0 + z1 * w^3)^2, (t2, t3) = (z2 + z3 * w^3)^2,
+    /// (t4, t5) = (z4 + z5 * w^3)^2, in Fp4 = Fp2[w^3], (w^3)^2 = xi
+    /// z0 = 3 * t0 - 2 * z0        z1 = 3 * t1 + 2 * z1
+    /// z2 = 3 * xi * t5 + 2 * z2   z3 = 3 * t4 - 2 * z3
+    /// z4 = 3 * t2 - 2 * z4        z5 = 3 * t3 + 2 * z5
+    /// ```
+    ///
+    /// The result is meaningless outside the cyclotomic subgroup.
+    pub fn cyclotomic_square(&self, a: &Fp12Element<T>) -> Fp12Element<T> {
+        let f = self.fp6.fp2();
+        let xi = self.fp6.non_residue();
+        let fp4_square = |x: &Fp2Element<T>, y: &Fp2Element<T>| {
+            let t = f.mul(x, y);
+            let s = f.mul(&f.add(x, y), &f.add(x, &f.mul(&xi, y)));
+            (f.sub(&f.sub(&s, &t), &f.mul(&xi, &t)), f.add(&t, &t))
+        };
+        // 3 * t - 2 * z and 3 * t + 2 * z
+        let minus = |t: &Fp2Element<T>, z: &Fp2Element<T>| {
+            let d = f.sub(t, z);
+            f.add(&f.add(&d, &d), t)
+        };
+        let plus = |t: &Fp2Element<T>, z: &Fp2Element<T>| {
+            let d = f.add(t, z);
+            f.add(&f.add(&d, &d), t)
+        };
+
+        let (z0, z4, z3) = (&a.c0.c0, &a.c0.c1, &a.c0.c2);
+        let (z2, z1, z5) = (&a.c1.c0, &a.c1.c1, &a.c1.c2);
+        let (t0, t1) = fp4_square(z0, z1);
+        let (t2, t3) = fp4_square(z2, z3);
+        let (t4, t5) = fp4_square(z4, z5);
+
+        let c0 = self
+            .fp6
+            .element(minus(&t0, z0), minus(&t2, z4), minus(&t4, z3));
+        let c1 = self
+            .fp6
+            .element(plus(&f.mul(&xi, &t5), z2), plus(&t1, z1), plus(&t3, z5));
+        self.element(c0, c1)
+    }
+
+    /// Computes a^e with cyclotomic squarings, a in the cyclotomic subgroup.
+    pub fn cyclotomic_exp(&self, a: &Fp12Element<T>, e: &BigUint) -> Fp12Element<T> {
+        let mut res = self.one();
+        for i in (0..e.bits()).rev() {
+            res = self.cyclotomic_square(&res);
+            if e.bit(i) {
+                res = self.mul(&res, a);
+            }
+        }
+        res
+    }
+
+    /// Computes the easy part f^((p^6 - 1) * (p^2 + 1)), None for zero.
+    ///
+    /// ```text
+    /// f^(p^6 - 1) = conj(f) * f^-1
+    /// g^(p^2 + 1) = frobenius(g, 2) * g
+    /// ```
+    ///
+    /// The result lies in the cyclotomic subgroup.
+    pub fn final_exponentiation_easy(&self, f: &Fp12Element<T>) -> Option<Fp12Element<T>> {
+        let g = self.mul(&self.conjugate(f), &self.inv(f)?);
+        Some(self.mul(&self.frobenius(&g, 2), &g))
+    }
+
+    /// Computes the hard part g^((p^4 - p^2 + 1) / r), g in the cyclotomic subgroup.
+    ///
+    /// ```text
+    /// Panics if r does not divide p^4 - p^2 + 1.
+    /// ```
+    pub fn final_exponentiation_hard(&self, g: &Fp12Element<T>, r: &BigUint) -> Fp12Element<T> {
+        let p = self
+            .fp6
+            .fp2()
+            .field()
+            .prime()
+            .to_bigint()
+            .to_biguint()
+            .unwrap();
+        let p2 = p.pow(2);
+        let phi12 = &p2 * &p2 - &p2 + BigUint::one();
+        let (e, rem) = phi12.divrem(r);
+        assert!(rem.is_zero(), "r must divide p^4 - p^2 + 1");
+        self.cyclotomic_exp(g, &e)
+    }
+
+    /// Computes f^((p^12 - 1) / r), the final exponentiation of a pairing.
+    /// Returns None for zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use algebra::bigint::BigUint;
+    /// use fields::field;
+    /// use fields::fp12;
+    /// use fields::fp2;
+    /// use fields::fp6;
+    ///
+    /// fn main() {
+    ///     let fp2 = fp2::Fp2::new(field::Field::new(103), -1);
+    ///     let fp12 = fp12::Fp12::new(fp6::Fp6::with_default_non_residue(fp2));
+    ///     let f = fp12.element(fp12.fp6().one(), fp12.fp6().one());
+    ///     // 103^4 - 103^2 + 1 = 37 * 97 * 31357
+    ///     let r = BigUint::from(31357u32);
+    ///     println!("{:?}", fp12.final_exponentiation(&f, &r));
+    /// }
+    /// ```
+    pub fn final_exponentiation(&self, f: &Fp12Element<T>, r: &BigUint) -> Option<Fp12Element<T>> {
+        let g = self.final_exponentiation_easy(f)?;
+        Some(self.final_exponentiation_hard(&g, r))
+    }
+}
+
+impl<T: Integer> Extension for Fp12<T> {
+    type Element = Fp12Element<T>;
+
+    fn add(&self, a: &Fp12Element<T>, b: &Fp12Element<T>) -> Fp12Element<T> {
+        Fp12::add(self, a, b)
+    }
+
+    fn sub(&self, a: &Fp12Element<T>, b: &Fp12Element<T>) -> Fp12Element<T> {
+        Fp12::sub(self, a, b)
+    }
+
+    fn mul(&self, a: &Fp12Element<T>, b: &Fp12Element<T>) -> Fp12Element<T> {
+        Fp12::mul(self, a, b)
+    }
+
+    fn neg(&self, a: &Fp12Element<T>) -> Fp12Element<T> {
+        Fp12::neg(self, a)
+    }
+
+    fn inv(&self, a: &Fp12Element<T>) -> Option<Fp12Element<T>> {
+        Fp12::inv(self, a)
+    }
+
+    fn exp(&self, a: &Fp12Element<T>, e: &BigUint) -> Fp12Element<T> {
+        Fp12::exp(self, a, e)
+    }
+
+    fn same(&self, other: &Self) -> bool {
+        self.fp6.same(&other.fp6)
+    }
+}
@@ -0,0 +1,383 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::fmt;
+
+use algebra::bigint::BigUint;
+use algebra::symbol::Symbol;
+use algebra::{arith, Integer};
+
+use crate::element::{Extension, ExtensionElement};
+use crate::field::Field;
+
+/// Element c0 + c1 * u of Fp2, u^2 = the non-residue.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fp2Element<T> {
+    pub c0: T,
+    pub c1: T,
+}
+
+impl<T: Integer> fmt::Display for Fp2Element<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + {}*u", self.c0, self.c1)
+    }
+}
+
+/// Quadratic extension field Fp2 = Fp[u] / (u^2 - beta).
+///
+/// ```text
+/// beta must be a quadratic non-residue in Fp, then u^2 - beta is irreducible
+/// and Fp2 has p^2 elements, every element of Fp is a square in Fp2.
+/// p = 3 (mod 4): beta = -1 is the usual choice, Fp2 looks like the complex numbers.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::fp2;
+///
+/// fn main() {
+///     let fp2 = fp2::Fp2::new(field::Field::new(31), -1);
+///     let a = fp2.element(3, 4);
+///     println!("{}", fp2.mul(&a, &a));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fp2<T> {
+    field: Field<T>,
+    beta: T,
+}
+
+impl<T: Integer> Fp2<T> {
+    /// Creates Fp2 with u^2 = non_residue.
+    ///
+    /// ```text
+    /// Panics if p = 2 or non_residue is a square in Fp.
+    /// ```
+    pub fn new(field: Field<T>, non_residue: T) -> Self {
+        let beta = arith::reduce(non_residue, field.prime());
+        assert!(field.prime() != T::two(), "Fp2 needs an odd prime");
+        assert!(
+            field.legendre_symbol(beta.clone()) == Symbol::MinusOne,
+            "u^2 must be a quadratic non-residue"
+        );
+        Fp2 { field, beta }
+    }
+
+    /// Creates Fp2 with the default non-residue:
+    /// -1 if p = 3 (mod 4), otherwise the least non-residue.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::fp2;
+    ///
+    /// fn main() {
+    ///     let fp2 = fp2::Fp2::with_default_non_residue(field::Field::new(37));
+    ///     println!("{:?}", fp2.non_residue());
+    /// }
+    /// ```
+    pub fn with_default_non_residue(field: Field<T>) -> Self {
+        let p = field.prime();
+        let beta = if p.bit(1) {
+            p - T::one()
+        } else {
+            let mut z = T::two();
+            while field.legendre_symbol(z.clone()) != Symbol::MinusOne {
+                z = z + T::one();
+            }
+            z
+        };
+        Fp2::new(field, beta)
+    }
+
+    /// Returns the base field Fp.
+    pub fn field(&self) -> &Field<T> {
+        &self.field
+    }
+
+    /// Returns beta, u^2 = beta.
+    pub fn non_residue(&self) -> T {
+        self.beta.clone()
+    }
+
+    /// Returns c0 + c1 * u, the coefficients reduced.
+    pub fn element(&self, c0: T, c1: T) -> Fp2Element<T> {
+        let p = self.field.prime();
+        Fp2Element {
+            c0: arith::reduce(c0, p.clone()),
+            c1: arith::reduce(c1, p),
+        }
+    }
+
+    /// Embeds a in Fp2, a + 0 * u.
+    pub fn from_base(&self, a: T) -> Fp2Element<T> {
+        self.element(a, T::zero())
+    }
+
+    pub fn zero(&self) -> Fp2Element<T> {
+        self.element(T::zero(), T::zero())
+    }
+
+    pub fn one(&self) -> Fp2Element<T> {
+        self.element(T::one(), T::zero())
+    }
+
+    pub fn is_zero(&self, a: &Fp2Element<T>) -> bool {
+        a.c0.is_zero() && a.c1.is_zero()
+    }
+
+    /// Binds a to the extension, with operators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::fp2;
+    ///
+    /// fn main() {
+    ///     let fp2 = fp2::Fp2::new(field::Field::new(31), -1);
+    ///     let a = fp2.bind(fp2.element(3, 4));
+    ///     println!("{}", &a * &a - a);
+    /// }
+    /// ```
+    pub fn bind(&self, a: Fp2Element<T>) -> ExtensionElement<'_, Fp2<T>> {
+        ExtensionElement::new(self, a)
+    }
+
+    pub fn add(&self, a: &Fp2Element<T>, b: &Fp2Element<T>) -> Fp2Element<T> {
+        let f = &self.field;
+        Fp2Element {
+            c0: f.add(a.c0.clone(), b.c0.clone()),
+            c1: f.add(a.c1.clone(), b.c1.clone()),
+        }
+    }
+
+    pub fn sub(&self, a: &Fp2Element<T>, b: &Fp2Element<T>) -> Fp2Element<T> {
+        let f = &self.field;
+        Fp2Element {
+            c0: f.sub(a.c0.clone(), b.c0.clone()),
+            c1: f.sub(a.c1.clone(), b.c1.clone()),
+        }
+    }
+
+    pub fn neg(&self, a: &Fp2Element<T>) -> Fp2Element<T> {
+        self.sub(&self.zero(), a)
+    }
+
+    /// Computes the product with Karatsuba, 3 base multiplications.
+    ///
+    /// ```text
+    /// v0 = a0 * b0, v1 = a1 * b1
+    /// c0 = v0 + beta * v1
+    /// c1 = (a0 + a1) * (b0 + b1) - v0 - v1
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::fp2;
+    ///
+    /// fn main() {
+    ///     let fp2 = fp2::Fp2::new(field::Field::new(31), -1);
+    ///     let c = fp2.mul(&fp2.element(1, 2), &fp2.element(3, 4));
+    ///     println!("{}", c);
+    /// }
+    /// ```
+    pub fn mul(&self, a: &Fp2Element<T>, b: &Fp2Element<T>) -> Fp2Element<T> {
+        let f = &self.field;
+        let v0 = f.mul(a.c0.clone(), b.c0.clone());
+        let v1 = f.mul(a.c1.clone(), b.c1.clone());
+        let s = f.mul(
+            f.add(a.c0.clone(), a.c1.clone()),
+            f.add(b.c0.clone(), b.c1.clone()),
+        );
+        Fp2Element {
+            c0: f.add(v0.clone(), f.mul(self.beta.clone(), v1.clone())),
+            c1: f.sub(f.sub(s, v0), v1),
+        }
+    }
+
+    /// Computes the square, complex squaring with 2 base multiplications.
+    ///
+    /// ```text
+    /// v = a0 * a1
+    /// c0 = (a0 + a1) * (a0 + beta * a1) - v - beta * v
+    /// c1 = 2 * v
+    /// ```
+    pub fn square(&self, a: &Fp2Element<T>) -> Fp2Element<T> {
+        let f = &self.field;
+        let v = f.mul(a.c0.clone(), a.c1.clone());
+        let bv = f.mul(self.beta.clone(), v.clone());
+        let t = f.mul(
+            f.add(a.c0.clone(), a.c1.clone()),
+            f.add(a.c0.clone(), f.mul(self.beta.clone(), a.c1.clone())),
+        );
+        Fp2Element {
+            c0: f.sub(f.sub(t, v.clone()), bv),
+            c1: f.add(v.clone(), v),
+        }
+    }
+
+    /// Multiplies by an element of the base field.
+    pub fn mul_base(&self, a: &Fp2Element<T>, k: T) -> Fp2Element<T> {
+        let f = &self.field;
+        Fp2Element {
+            c0: f.mul(a.c0.clone(), k.clone()),
+            c1: f.mul(a.c1.clone(), k),
+        }
+    }
+
+    /// Returns the conjugate c0 - c1 * u.
+    pub fn conjugate(&self, a: &Fp2Element<T>) -> Fp2Element<T> {
+        Fp2Element {
+            c0: a.c0.clone(),
+            c1: self.field.sub(T::zero(), a.c1.clone()),
+        }
+    }
+
+    /// Returns the norm a * conj(a) = c0^2 - beta * c1^2, which lies in Fp.
+    pub fn norm(&self, a: &Fp2Element<T>) -> T {
+        let f = &self.field;
+        let c0c0 = f.mul(a.c0.clone(), a.c0.clone());
+        let c1c1 = f.mul(a.c1.clone(), a.c1.clone());
+        f.sub(c0c0, f.mul(self.beta.clone(), c1c1))
+    }
+
+    /// Computes the inverse, None for zero.
+    ///
+    /// ```text
+    /// a^-1 = conj(a) / norm(a)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::fp2;
+    ///
+    /// fn main() {
+    ///     let fp2 = fp2::Fp2::new(field::Field::new(31), -1);
+    ///     println!("{:?}", fp2.inv(&fp2.element(3, 4)));
+    /// }
+    /// ```
+    pub fn inv(&self, a: &Fp2Element<T>) -> Option<Fp2Element<T>> {
+        let n = self.field.inv(self.norm(a))?;
+        Some(self.mul_base(&self.conjugate(a), n))
+    }
+
+    /// Computes a / b, None if b is zero.
+    pub fn div(&self, a: &Fp2Element<T>, b: &Fp2Element<T>) -> Option<Fp2Element<T>> {
+        Some(self.mul(a, &self.inv(b)?))
+    }
+
+    /// Computes a^e by square-and-multiply, e may exceed p.
+    pub fn exp(&self, a: &Fp2Element<T>, e: &BigUint) -> Fp2Element<T> {
+        let mut res = self.one();
+        for i in (0..e.bits()).rev() {
+            res = self.square(&res);
+            if e.bit(i) {
+                res = self.mul(&res, a);
+            }
+        }
+        res
+    }
+
+    /// Applies the Frobenius map a -> a^(p^power).
+    ///
+    /// ```text
+    /// u^p = beta^((p - 1) / 2) * u = -u, beta is a non-residue,
+    /// so a^p = conj(a) and the map has order 2.
+    /// ```
+    pub fn frobenius(&self, a: &Fp2Element<T>, power: usize) -> Fp2Element<T> {
+        if power % 2 == 1 {
+            self.conjugate(a)
+        } else {
+            a.clone()
+        }
+    }
+
+    /// Returns true if a is a square in Fp2, that is norm(a) is a square in Fp.
+    pub fn is_square(&self, a: &Fp2Element<T>) -> bool {
+        self.field.legendre_symbol(self.norm(a)) != Symbol::MinusOne
+    }
+
+    /// Computes a square root, None if a is not a square.
+    ///
+    /// ```text
+    /// c1 = 0: sqrt(c0) if c0 is a square in Fp, else sqrt(c0 / beta) * u
+    /// otherwise, the complex method:
+    ///     s = sqrt(norm(a)) in Fp
+    ///     x0 = sqrt((c0 + s) / 2), or sqrt((c0 - s) / 2) if that is no square
+    ///     x1 = c1 / (2 * x0)
+    ///     (x0 + x1 * u)^2 = x0^2 + beta * x1^2 + 2 * x0 * x1 * u = a
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::fp2;
+    ///
+    /// fn main() {
+    ///     let fp2 = fp2::Fp2::new(field::Field::new(31), -1);
+    ///     // 3 is not a square in Fp31, but in Fp2.
+    ///     println!("{:?}", fp2.sqrt(&fp2.from_base(3)));
+    /// }
+    /// ```
+    pub fn sqrt(&self, a: &Fp2Element<T>) -> Option<Fp2Element<T>> {
+        let f = &self.field;
+        if a.c1.is_zero() {
+            return match f.sqrt(a.c0.clone()) {
+                Some(x) => Some(self.element(x, T::zero())),
+                None => {
+                    let y = f.sqrt(f.div(a.c0.clone(), self.beta.clone())?)?;
+                    Some(self.element(T::zero(), y))
+                }
+            };
+        }
+
+        let s = f.sqrt(self.norm(a))?;
+        let half = f.inv(T::two())?;
+        let x0 = f
+            .sqrt(f.mul(f.add(a.c0.clone(), s.clone()), half.clone()))
+            .or_else(|| f.sqrt(f.mul(f.sub(a.c0.clone(), s), half)))?;
+        let x1 = f.div(a.c1.clone(), f.add(x0.clone(), x0.clone()))?;
+        Some(self.element(x0, x1))
+    }
+}
+
+impl<T: Integer> Extension for Fp2<T> {
+    type Element = Fp2Element<T>;
+
+    fn add(&self, a: &Fp2Element<T>, b: &Fp2Element<T>) -> Fp2Element<T> {
+        Fp2::add(self, a, b)
+    }
+
+    fn sub(&self, a: &Fp2Element<T>, b: &Fp2Element<T>) -> Fp2Element<T> {
+        Fp2::sub(self, a, b)
+    }
+
+    fn mul(&self, a: &Fp2Element<T>, b: &Fp2Element<T>) -> Fp2Element<T> {
+        Fp2::mul(self, a, b)
+    }
+
+    fn neg(&self, a: &Fp2Element<T>) -> Fp2Element<T> {
+        Fp2::neg(self, a)
+    }
+
+    fn inv(&self, a: &Fp2Element<T>) -> Option<Fp2Element<T>> {
+        Fp2::inv(self, a)
+    }
+
+    fn exp(&self, a: &Fp2Element<T>, e: &BigUint) -> Fp2Element<T> {
+        Fp2::exp(self, a, e)
+    }
+
+    fn same(&self, other: &Self) -> bool {
+        self.field.prime() == other.field.prime() && self.beta == other.beta
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::bigint::BigUint;
+use algebra::Integer;
+
+use crate::element::{Extension, ExtensionElement};
+use crate::fp2::{Fp2, Fp2Element};
+
+/// Element c0 + c1 * v + c2 * v^2 of Fp6, v^3 = xi.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fp6Element<T> {
+    pub c0: Fp2Element<T>,
+    pub c1: Fp2Element<T>,
+    pub c2: Fp2Element<T>,
+}
+
+/// Cubic extension Fp6 = Fp2[v] / (v^3 - xi) of Fp2.
+///
+/// ```text
+/// xi must not be a cube in Fp2, and p = 1 (mod 3) so that
+/// v^(p^i) = xi^((p^i - 1) / 3) * v stays a multiple of v.
+/// Pairing-friendly (BN, BLS12) primes all satisfy p = 1 (mod 6).
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::fp2;
+/// use fields::fp6;
+///
+/// fn main() {
+///     let fp2 = fp2::Fp2::new(field::Field::new(103), -1);
+///     let fp6 = fp6::Fp6::with_default_non_residue(fp2);
+///     let a = fp6.element(fp6.fp2().element(1, 2), fp6.fp2().one(), fp6.fp2().zero());
+///     println!("{:?}", fp6.mul(&a, &a));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fp6<T> {
+    fp2: Fp2<T>,
+    xi: Fp2Element<T>,
+    // frobenius[i] = (xi^((p^i - 1) / 3), xi^(2 * (p^i - 1) / 3)), i < 6
+    frobenius: Vec<(Fp2Element<T>, Fp2Element<T>)>,
+}
+
+impl<T: Integer> Fp6<T> {
+    /// Creates Fp6 with v^3 = xi.
+    ///
+    /// ```text
+    /// Panics if p != 1 (mod 3) or xi is a cube in Fp2.
+    /// ```
+    pub fn new(fp2: Fp2<T>, xi: Fp2Element<T>) -> Self {
+        let p = fp2.field().prime().to_bigint().to_biguint().unwrap();
+        let three = BigUint::from(3u32);
+        assert!((&p % &three).is_one(), "Fp6 needs a prime p = 1 (mod 3)");
+        let cube_test = (p.pow(2) - BigUint::one()) / three.clone();
+        assert!(
+            fp2.exp(&xi, &cube_test) != fp2.one(),
+            "v^3 must not be a cube"
+        );
+
+        let frobenius = (0..6)
+            .map(|i| {
+                let e = (p.pow(i) - BigUint::one()) / three.clone();
+                let g1 = fp2.exp(&xi, &e);
+                let g2 = fp2.square(&g1);
+                (g1, g2)
+            })
+            .collect();
+        Fp6 { fp2, xi, frobenius }
+    }
+
+    /// Creates Fp6 with the least xi = k + u which is neither a square nor a cube,
+    /// so the same xi also builds Fp12 = Fp6[w] / (w^2 - v).
+    pub fn with_default_non_residue(fp2: Fp2<T>) -> Self {
+        let p = fp2.field().prime().to_bigint().to_biguint().unwrap();
+        let cube_test = (p.pow(2) - BigUint::one()) / BigUint::from(3u32);
+        let mut k = T::zero();
+        let xi = loop {
+            let xi = fp2.element(k.clone(), T::one());
+            if !fp2.is_square(&xi) && fp2.exp(&xi, &cube_test) != fp2.one() {
+                break xi;
+            }
+            k = k + T::one();
+        };
+        Fp6::new(fp2, xi)
+    }
+
+    /// Returns the base field Fp2.
+    pub fn fp2(&self) -> &Fp2<T> {
+        &self.fp2
+    }
+
+    /// Returns xi, v^3 = xi.
+    pub fn non_residue(&self) -> Fp2Element<T> {
+        self.xi.clone()
+    }
+
+    pub fn element(
+        &self,
+        c0: Fp2Element<T>,
+        c1: Fp2Element<T>,
+        c2: Fp2Element<T>,
+    ) -> Fp6Element<T> {
+        Fp6Element { c0, c1, c2 }
+    }
+
+    /// Embeds a in Fp6, a + 0 * v + 0 * v^2.
+    pub fn from_fp2(&self, a: Fp2Element<T>) -> Fp6Element<T> {
+        self.element(a, self.fp2.zero(), self.fp2.zero())
+    }
+
+    pub fn zero(&self) -> Fp6Element<T> {
+        self.from_fp2(self.fp2.zero())
+    }
+
+    pub fn one(&self) -> Fp6Element<T> {
+        self.from_fp2(self.fp2.one())
+    }
+
+    pub fn is_zero(&self, a: &Fp6Element<T>) -> bool {
+        self.fp2.is_zero(&a.c0) && self.fp2.is_zero(&a.c1) && self.fp2.is_zero(&a.c2)
+    }
+
+    /// Binds a to the extension, with operators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::fp2;
+    /// use fields::fp6;
+    ///
+    /// fn main() {
+    ///     let fp2 = fp2::Fp2::new(field::Field::new(103), -1);
+    ///     let fp6 = fp6::Fp6::with_default_non_residue(fp2);
+    ///     let f = fp6.fp2();
+    ///     let v = fp6.bind(fp6.element(f.zero(), f.one(), f.zero()));
+    ///     println!("{:?}", &v * &v * v);
+    /// }
+    /// ```
+    pub fn bind(&self, a: Fp6Element<T>) -> ExtensionElement<'_, Fp6<T>> {
+        ExtensionElement::new(self, a)
+    }
+
+    pub fn add(&self, a: &Fp6Element<T>, b: &Fp6Element<T>) -> Fp6Element<T> {
+        let f = &self.fp2;
+        self.element(
+            f.add(&a.c0, &b.c0),
+            f.add(&a.c1, &b.c1),
+            f.add(&a.c2, &b.c2),
+        )
+    }
+
+    pub fn sub(&self, a: &Fp6Element<T>, b: &Fp6Element<T>) -> Fp6Element<T> {
+        let f = &self.fp2;
+        self.element(
+            f.sub(&a.c0, &b.c0),
+            f.sub(&a.c1, &b.c1),
+            f.sub(&a.c2, &b.c2),
+        )
+    }
+
+    pub fn neg(&self, a: &Fp6Element<T>) -> Fp6Element<T> {
+        self.sub(&self.zero(), a)
+    }
+
+    /// Computes the product with Karatsuba, 6 Fp2 multiplications.
+    ///
+    /// ```text
+    /// v0 = a0 * b0, v1 = a1 * b1, v2 = a2 * b2
+    /// c0 = v0 + xi * ((a1 + a2) * (b1 + b2) - v1 - v2)
+    /// c1 = (a0 + a1) * (b0 + b1) - v0 - v1 + xi * v2
+    /// c2 = (a0 + a2) * (b0 + b2) - v0 - v2 + v1
+    /// ```
+    pub fn mul(&self, a: &Fp6Element<T>, b: &Fp6Element<T>) -> Fp6Element<T> {
+        let f = &self.fp2;
+        let v0 = f.mul(&a.c0, &b.c0);
+        let v1 = f.mul(&a.c1, &b.c1);
+        let v2 = f.mul(&a.c2, &b.c2);
+
+        let t = f.mul(&f.add(&a.c1, &a.c2), &f.add(&b.c1, &b.c2));
+        let c0 = f.add(&v0, &f.mul(&self.xi, &f.sub(&f.sub(&t, &v1), &v2)));
+        let t = f.mul(&f.add(&a.c0, &a.c1), &f.add(&b.c0, &b.c1));
+        let c1 = f.add(&f.sub(&f.sub(&t, &v0), &v1), &f.mul(&self.xi, &v2));
+        let t = f.mul(&f.add(&a.c0, &a.c2), &f.add(&b.c0, &b.c2));
+        let c2 = f.add(&f.sub(&f.sub(&t, &v0), &v2), &v1);
+        self.element(c0, c1, c2)
+    }
+
+    pub fn square(&self, a: &Fp6Element<T>) -> Fp6Element<T> {
+        self.mul(a, a)
+    }
+
+    /// Multiplies by v: (a0 + a1 * v + a2 * v^2) * v = xi * a2 + a0 * v + a1 * v^2.
+    pub fn mul_by_v(&self, a: &Fp6Element<T>) -> Fp6Element<T> {
+        self.element(self.fp2.mul(&self.xi, &a.c2), a.c0.clone(), a.c1.clone())
+    }
+
+    /// Multiplies every coefficient by k in Fp2.
+    pub fn mul_fp2(&self, a: &Fp6Element<T>, k: &Fp2Element<T>) -> Fp6Element<T> {
+        let f = &self.fp2;
+        self.element(f.mul(&a.c0, k), f.mul(&a.c1, k), f.mul(&a.c2, k))
+    }
+
+    /// Computes the inverse, None for zero.
+    ///
+    /// ```text
+    /// t0 = a0^2 - xi * a1 * a2
+    /// t1 = xi * a2^2 - a0 * a1
+    /// t2 = a1^2 - a0 * a2
+    /// a * (t0 + t1 * v + t2 * v^2) = a0 * t0 + xi * (a2 * t1 + a1 * t2), in Fp2
+    /// ```
+    pub fn inv(&self, a: &Fp6Element<T>) -> Option<Fp6Element<T>> {
+        let f = &self.fp2;
+        let t0 = f.sub(&f.square(&a.c0), &f.mul(&self.xi, &f.mul(&a.c1, &a.c2)));
+        let t1 = f.sub(&f.mul(&self.xi, &f.square(&a.c2)), &f.mul(&a.c0, &a.c1));
+        let t2 = f.sub(&f.square(&a.c1), &f.mul(&a.c0, &a.c2));
+        let n = f.add(
+            &f.mul(&a.c0, &t0),
+            &f.mul(&self.xi, &f.add(&f.mul(&a.c2, &t1), &f.mul(&a.c1, &t2))),
+        );
+        let n = f.inv(&n)?;
+        Some(self.mul_fp2(&self.element(t0, t1, t2), &n))
+    }
+
+    /// Computes a^e by square-and-multiply.
+    pub fn exp(&self, a: &Fp6Element<T>, e: &BigUint) -> Fp6Element<T> {
+        let mut res = self.one();
+        for i in (0..e.bits()).rev() {
+            res = self.square(&res);
+            if e.bit(i) {
+                res = self.mul(&res, a);
+            }
+        }
+        res
+    }
+
+    /// Applies the Frobenius map a -> a^(p^power).
+    ///
+    /// ```text
+    /// (a0 + a1 * v + a2 * v^2)^(p^i)
+    ///     = a0^(p^i) + a1^(p^i) * g1 * v + a2^(p^i) * g2 * v^2
+    /// g1 = xi^((p^i - 1) / 3), g2 = g1^2, precomputed for i < 6.
+    /// ```
+    pub fn frobenius(&self, a: &Fp6Element<T>, power: usize) -> Fp6Element<T> {
+        let f = &self.fp2;
+        let (g1, g2) = &self.frobenius[power % 6];
+        self.element(
+            f.frobenius(&a.c0, power),
+            f.mul(&f.frobenius(&a.c1, power), g1),
+            f.mul(&f.frobenius(&a.c2, power), g2),
+        )
+    }
+}
+
+impl<T: Integer> Extension for Fp6<T> {
+    type Element = Fp6Element<T>;
+
+    fn add(&self, a: &Fp6Element<T>, b: &Fp6Element<T>) -> Fp6Element<T> {
+        Fp6::add(self, a, b)
+    }
+
+    fn sub(&self, a: &Fp6Element<T>, b: &Fp6Element<T>) -> Fp6Element<T> {
+        Fp6::sub(self, a, b)
+    }
+
+    fn mul(&self, a: &Fp6Element<T>, b: &Fp6Element<T>) -> Fp6Element<T> {
+        Fp6::mul(self, a, b)
+    }
+
+    fn neg(&self, a: &Fp6Element<T>) -> Fp6Element<T> {
+        Fp6::neg(self, a)
+    }
+
+    fn inv(&self, a: &Fp6Element<T>) -> Option<Fp6Element<T>> {
+        Fp6::inv(self, a)
+    }
+
+    fn exp(&self, a: &Fp6Element<T>, e: &BigUint) -> Fp6Element<T> {
+        Fp6::exp(self, a, e)
+    }
+
+    fn same(&self, other: &Self) -> bool {
+        self.fp2.same(&other.fp2) && self.xi == other.xi
+    }
+}
@@ -0,0 +1,262 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use crate::field::Field;
+use crate::poly::Polynomial;
+
+/// Binary field GF(2^m) = F2[x] / (f), f a trinomial or pentanomial.
+///
+/// ```text
+/// f = x^m + x^k + 1, or x^m + x^k1 + x^k2 + x^k3 + 1, irreducible over F2.
+/// Elements are polynomials of degree < m packed into the bits of an u64,
+/// bit i is the coefficient of x^i, 2 <= m <= 63.
+/// Addition is xor, multiplication is carry-less followed by reduction:
+///     x^m = x^k + 1 (mod f), the high bits fold back with shifts and xors.
+/// AES: GF(2^8) with x^8 + x^4 + x^3 + x + 1.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::gf2m;
+///
+/// fn main() {
+///     let aes = gf2m::GF2m::aes();
+///     // {57} * {83} = {c1}, FIPS-197
+///     println!("{:x}", aes.mul(0x57, 0x83));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GF2m {
+    m: usize,
+    // exponents k of the middle terms, m > k > 0
+    terms: Vec<usize>,
+}
+
+impl GF2m {
+    /// Creates GF(2^m) reduced by x^m + sum(x^k for k in terms) + 1.
+    ///
+    /// ```text
+    /// Panics if m is out of 2..=63, terms is not 1 (trinomial) or
+    /// 3 (pentanomial) distinct exponents in 1..m, or the polynomial is reducible.
+    /// ```
+    pub fn new(m: usize, terms: &[usize]) -> Self {
+        assert!((2..=63).contains(&m), "GF2m needs 2 <= m <= 63");
+        let mut terms = terms.to_vec();
+        terms.sort_unstable_by(|a, b| b.cmp(a));
+        terms.dedup();
+        assert!(
+            (terms.len() == 1 || terms.len() == 3) && terms.iter().all(|&k| k > 0 && k < m),
+            "reduction polynomial must be a trinomial or pentanomial"
+        );
+
+        let fp = Field::new(2i64);
+        let mut coeffs = vec![0; m + 1];
+        coeffs[0] = 1;
+        coeffs[m] = 1;
+        for &k in terms.iter() {
+            coeffs[k] = 1;
+        }
+        assert!(
+            Polynomial::new(&fp, coeffs).is_irreducible(),
+            "reduction polynomial must be irreducible"
+        );
+        GF2m { m, terms }
+    }
+
+    /// Creates the AES field GF(2^8) = F2[x] / (x^8 + x^4 + x^3 + x + 1).
+    pub fn aes() -> Self {
+        GF2m::new(8, &[4, 3, 1])
+    }
+
+    /// Returns m, the field has 2^m elements.
+    pub fn degree(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the reduction polynomial as a bit mask, bit m set.
+    pub fn modulus(&self) -> u64 {
+        self.terms
+            .iter()
+            .fold((1 << self.m) | 1, |acc, &k| acc | (1 << k))
+    }
+
+    /// Reduces a, any polynomial of degree < 64.
+    pub fn element(&self, a: u64) -> u64 {
+        self.reduce(a as u128)
+    }
+
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        a ^ b
+    }
+
+    /// Same as add, -1 = 1 in characteristic 2.
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        a ^ b
+    }
+
+    /// Computes a * b, carry-less product then reduction.
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        let (a, mut b) = (a as u128, b);
+        let mut c = 0u128;
+        let mut i = 0;
+        while b != 0 {
+            if b & 1 == 1 {
+                c ^= a << i;
+            }
+            b >>= 1;
+            i += 1;
+        }
+        self.reduce(c)
+    }
+
+    /// Computes a^2, which only spreads the bits: (sum a_i x^i)^2 = sum a_i x^(2i).
+    pub fn square(&self, a: u64) -> u64 {
+        let c = (0..64)
+            .filter(|&i| (a >> i) & 1 == 1)
+            .fold(0u128, |c, i| c | (1 << (2 * i)));
+        self.reduce(c)
+    }
+
+    /// Computes a^e by square-and-multiply.
+    pub fn exp(&self, a: u64, e: u64) -> u64 {
+        let mut res = 1;
+        for i in (0..64 - e.leading_zeros()).rev() {
+            res = self.square(res);
+            if (e >> i) & 1 == 1 {
+                res = self.mul(res, a);
+            }
+        }
+        res
+    }
+
+    /// Computes the inverse with Itoh-Tsujii, None for zero.
+    ///
+    /// ```text
+    /// a^-1 = a^(2^m - 2) = (a^(2^(m-1) - 1))^2
+    /// b(k) = a^(2^k - 1), built along the bits of m - 1:
+    ///     b(2k) = b(k)^(2^k) * b(k)
+    ///     b(k + 1) = b(k)^2 * a
+    /// Only O(log m) multiplications, the rest are squarings.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::gf2m;
+    ///
+    /// fn main() {
+    ///     let aes = gf2m::GF2m::aes();
+    ///     println!("{:x?}", aes.inv(0x53));
+    /// }
+    /// ```
+    pub fn inv(&self, a: u64) -> Option<u64> {
+        let a = self.element(a);
+        if a == 0 {
+            return None;
+        }
+        let n = self.m - 1;
+        let mut b = a;
+        let mut k = 1;
+        for i in (0..(usize::BITS - n.leading_zeros() - 1) as usize).rev() {
+            let mut t = b;
+            for _ in 0..k {
+                t = self.square(t);
+            }
+            b = self.mul(t, b);
+            k *= 2;
+            if (n >> i) & 1 == 1 {
+                b = self.mul(self.square(b), a);
+                k += 1;
+            }
+        }
+        Some(self.square(b))
+    }
+
+    /// Computes a / b, None if b is zero.
+    pub fn div(&self, a: u64, b: u64) -> Option<u64> {
+        Some(self.mul(a, self.inv(b)?))
+    }
+
+    /// Computes the unique square root a^(2^(m-1)).
+    pub fn sqrt(&self, a: u64) -> u64 {
+        (1..self.m).fold(self.element(a), |r, _| self.square(r))
+    }
+
+    /// Computes the trace a + a^2 + a^4 + ... + a^(2^(m-1)), which is 0 or 1.
+    pub fn trace(&self, a: u64) -> u64 {
+        let mut t = self.element(a);
+        let mut s = t;
+        for _ in 1..self.m {
+            t = self.square(t);
+            s ^= t;
+        }
+        s
+    }
+
+    /// Computes the half-trace a + a^4 + a^16 + ... + a^(2^(m-1)), m odd.
+    ///
+    /// ```text
+    /// h = H(c) satisfies h^2 + h = c + Tr(c).
+    /// Panics if m is even.
+    /// ```
+    pub fn half_trace(&self, a: u64) -> u64 {
+        assert!(self.m % 2 == 1, "half-trace needs an odd m");
+        let mut t = self.element(a);
+        let mut s = t;
+        for _ in 0..(self.m - 1) / 2 {
+            t = self.square(self.square(t));
+            s ^= t;
+        }
+        s
+    }
+
+    /// Solves z^2 + z = c, None if Tr(c) = 1.
+    ///
+    /// ```text
+    /// The other root is z + 1.
+    /// m odd: z = H(c), the half-trace.
+    /// m even: pick d with Tr(d) = 1, then
+    ///     z = sum_{i=0}^{m-2} (sum_{j=i+1}^{m-1} d^(2^j)) * c^(2^i)
+    /// ```
+    ///
+    /// The point decompression of binary curves y^2 + xy = x^3 + ax^2 + b
+    /// reduces to this equation.
+    pub fn solve_quadratic(&self, c: u64) -> Option<u64> {
+        let c = self.element(c);
+        if self.trace(c) == 1 {
+            return None;
+        }
+        if self.m % 2 == 1 {
+            return Some(self.half_trace(c));
+        }
+
+        let d = (2..).find(|&d| self.trace(d) == 1).unwrap();
+        // d2[j] = d^(2^j), c2[i] = c^(2^i)
+        let mut d2 = vec![d];
+        let mut c2 = vec![c];
+        for i in 1..self.m {
+            d2.push(self.square(d2[i - 1]));
+            c2.push(self.square(c2[i - 1]));
+        }
+        let mut z = 0;
+        let mut inner = 0;
+        for i in (0..self.m - 1).rev() {
+            inner ^= d2[i + 1];
+            z ^= self.mul(inner, c2[i]);
+        }
+        Some(z)
+    }
+
+    fn reduce(&self, mut c: u128) -> u64 {
+        let mask = (1u128 << self.m) - 1;
+        while c >> self.m != 0 {
+            let hi = c >> self.m;
+            c = (c & mask) ^ hi;
+            for &k in self.terms.iter() {
+                c ^= hi << k;
+            }
+        }
+        c as u64
+    }
+}
@@ -1,4 +1,12 @@
 // Copyright (c) BohuTANG
 // Code is licensed with BSD
 
+pub mod element;
 pub mod field;
+pub mod fp12;
+pub mod fp2;
+pub mod fp6;
+pub mod gf2m;
+pub mod ntt;
+pub mod poly;
+pub mod poly_factor;
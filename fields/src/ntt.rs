@@ -0,0 +1,380 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::symbol::Symbol;
+use algebra::{arith, Integer};
+
+use crate::field::Field;
+use crate::poly::Polynomial;
+
+/// Returns the 2-adicity s of the field, p - 1 = q * 2^s with q odd.
+///
+/// ```text
+/// A primitive 2^k-th root of unity exists if and only if k <= s.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::ntt;
+///
+/// fn main() {
+///     // 998244353 = 119 * 2^23 + 1
+///     let fp = field::Field::new(998244353i64);
+///     println!("{:?}", ntt::two_adicity(&fp));
+/// }
+/// ```
+pub fn two_adicity<T: Integer>(field: &Field<T>) -> usize {
+    let p1 = field.prime() - T::one();
+    (0..p1.bits()).find(|&i| p1.bit(i)).unwrap_or(0)
+}
+
+/// Returns a primitive 2^log_n-th root of unity, None if 2^log_n does not divide p - 1.
+///
+/// ```text
+/// z: a quadratic non-residue, z^((p - 1) / 2) = -1
+/// w = z^((p - 1) / 2^k)
+/// w^(2^(k-1)) = z^((p - 1) / 2) = -1, so w has order exactly 2^k.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::ntt;
+///
+/// fn main() {
+///     let fp = field::Field::new(257);
+///     println!("{:?}", ntt::root_of_unity(&fp, 8));
+/// }
+/// ```
+pub fn root_of_unity<T: Integer>(field: &Field<T>, log_n: usize) -> Option<T> {
+    if log_n == 0 {
+        return Some(T::one());
+    }
+    if log_n > two_adicity(field) {
+        return None;
+    }
+    let mut z = T::two();
+    while field.legendre_symbol(z.clone()) != Symbol::MinusOne {
+        z = z + T::one();
+    }
+    Some(field.exp(z, (field.prime() - T::one()) >> log_n))
+}
+
+/// Computes the number-theoretic transform, the length must be a power of two.
+///
+/// ```text
+/// A[j] = sum a[i] * w^(i * j), w a primitive n-th root of unity
+/// that is, the evaluations of a(x) at 1, w, w^2, ..., w^(n-1).
+/// Returns None if the field has no n-th root of unity.
+/// ```
+///
+/// ```text
+/// Iterative Cooley-Tukey, O(n log n):
+/// bit-reversal permutation, then for len = 2, 4, ..., n
+/// butterflies with wl = w^(n / len):
+///     u = a[i + j], v = a[i + j + len/2] * wl^j
+///     a[i + j] = u + v, a[i + j + len/2] = u - v
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::ntt;
+///
+/// fn main() {
+///     let fp = field::Field::new(17);
+///     println!("{:?}", ntt::ntt(&fp, &[1, 2, 3, 4]));
+/// }
+/// ```
+pub fn ntt<T: Integer>(field: &Field<T>, values: &[T]) -> Option<Vec<T>> {
+    let w = root_of_unity(field, log2_exact(values.len()))?;
+    Some(transform(field, values, w))
+}
+
+/// Computes the inverse number-theoretic transform.
+///
+/// ```text
+/// a[i] = n^-1 * sum A[j] * w^(-i * j)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::ntt;
+///
+/// fn main() {
+///     let fp = field::Field::new(17);
+///     let a = ntt::ntt(&fp, &[1, 2, 3, 4]).unwrap();
+///     println!("{:?}", ntt::intt(&fp, &a));
+/// }
+/// ```
+pub fn intt<T: Integer>(field: &Field<T>, values: &[T]) -> Option<Vec<T>> {
+    let p = field.prime();
+    let w = root_of_unity(field, log2_exact(values.len()))?;
+    let a = transform(field, values, arith::mod_inv(w, p.clone()));
+    // n divides p - 1, so it fits in T.
+    let n = T::from_i64(values.len() as i64).unwrap();
+    let n_inv = arith::mod_inv(n, p);
+    Some(a.into_iter().map(|x| field.mul(x, n_inv.clone())).collect())
+}
+
+/// Computes the product of two polynomials with the NTT.
+///
+/// ```text
+/// n = next power of two > deg(f) + deg(g)
+/// f * g = intt(ntt(f) . ntt(g)), . the pointwise product
+/// O(n log n) instead of O(n^2) for Polynomial::mul.
+/// Returns None if the field has no n-th root of unity.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::ntt;
+/// use fields::poly::Polynomial;
+///
+/// fn main() {
+///     let fp = field::Field::new(998244353i64);
+///     let f = Polynomial::new(&fp, vec![1, 2, 3]);
+///     let g = Polynomial::new(&fp, vec![4, 5]);
+///     println!("{:?}", ntt::mul(&f, &g).unwrap().coeffs());
+/// }
+/// ```
+pub fn mul<'a, T: Integer>(
+    f: &Polynomial<'a, T>,
+    g: &Polynomial<'a, T>,
+) -> Option<Polynomial<'a, T>> {
+    let field = f.field();
+    if f.is_zero() || g.is_zero() {
+        return Some(Polynomial::zero(field));
+    }
+    let n = (f.coeffs().len() + g.coeffs().len() - 1).next_power_of_two();
+    let a = ntt(field, &padded(f, n))?;
+    let b = ntt(field, &padded(g, n))?;
+    let c: Vec<T> = a.into_iter().zip(b).map(|(x, y)| field.mul(x, y)).collect();
+    Some(Polynomial::new(field, intt(field, &c)?))
+}
+
+/// Computes the quotient and remainder with Newton iteration and NTT products.
+///
+/// ```text
+/// n = deg(f), m = deg(g), rev(f) = x^n * f(1/x)
+/// rev(q) = rev(f) / rev(g) (mod x^(n - m + 1))
+/// 1 / rev(g) by Newton: h(2k) = h(k) * (2 - rev(g) * h(k)) (mod x^2k)
+/// r = f - q * g
+/// Panics if g is zero, returns None if the field lacks the roots of unity.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::ntt;
+/// use fields::poly::Polynomial;
+///
+/// fn main() {
+///     let fp = field::Field::new(998244353i64);
+///     let f = Polynomial::new(&fp, vec![3, 2, 1]);
+///     let g = Polynomial::new(&fp, vec![1, 1]);
+///     let (q, r) = ntt::divrem(&f, &g).unwrap();
+///     println!("{:?} {:?}", q.coeffs(), r.coeffs());
+/// }
+/// ```
+pub fn divrem<'a, T: Integer>(
+    f: &Polynomial<'a, T>,
+    g: &Polynomial<'a, T>,
+) -> Option<(Polynomial<'a, T>, Polynomial<'a, T>)> {
+    assert!(!g.is_zero(), "division by the zero polynomial");
+    let field = f.field();
+    let (n, m) = match (f.degree(), g.degree()) {
+        (Some(n), Some(m)) if n >= m => (n, m),
+        _ => return Some((Polynomial::zero(field), f.clone())),
+    };
+    let k = n - m + 1;
+    let rev_g = reversed(g, m + 1);
+
+    let mut h = Polynomial::new(
+        field,
+        vec![arith::mod_inv(g.leading_coefficient(), field.prime())],
+    );
+    let two = Polynomial::new(field, vec![T::two()]);
+    let mut len = 1;
+    while len < k {
+        len *= 2;
+        let e = two.sub(&truncated(&mul(&truncated(&rev_g, len), &h)?, len));
+        h = truncated(&mul(&h, &e)?, len);
+    }
+
+    let rev_q = truncated(&mul(&reversed(f, n + 1), &h)?, k);
+    let q = reversed(&rev_q, k);
+    let r = f.sub(&mul(&q, g)?);
+    Some((q, r))
+}
+
+/// Evaluates f at all the 2^log_n-th roots of unity 1, w, ..., w^(n-1).
+///
+/// ```text
+/// w^n = 1, so f is first folded mod x^n - 1 if deg(f) >= n.
+/// Returns None if the field has no n-th root of unity.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::ntt;
+/// use fields::poly::Polynomial;
+///
+/// fn main() {
+///     let fp = field::Field::new(17);
+///     let f = Polynomial::new(&fp, vec![1, 2, 3]);
+///     println!("{:?}", ntt::evaluate_domain(&f, 2));
+/// }
+/// ```
+pub fn evaluate_domain<T: Integer>(f: &Polynomial<T>, log_n: usize) -> Option<Vec<T>> {
+    let field = f.field();
+    let n = 1 << log_n;
+    let mut a = vec![T::zero(); n];
+    for (i, c) in f.coeffs().iter().enumerate() {
+        a[i % n] = field.add(a[i % n].clone(), c.clone());
+    }
+    ntt(field, &a)
+}
+
+/// Evaluates f at arbitrary points with a subproduct tree.
+///
+/// ```text
+/// up:   leaves x - xi, every node is the product of its children (ntt::mul)
+/// down: f mod root, then the remainder of the parent mod each child
+///       (ntt::divrem), at the leaves f mod (x - xi) = f(xi)
+/// O(M(n) log n) with M(n) the cost of a product.
+/// Returns None if the field lacks the roots of unity for the products.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::ntt;
+/// use fields::poly::Polynomial;
+///
+/// fn main() {
+///     let fp = field::Field::new(998244353i64);
+///     let f = Polynomial::new(&fp, vec![1, 2, 3]);
+///     println!("{:?}", ntt::multi_eval(&f, &[5, 6, 7]));
+/// }
+/// ```
+pub fn multi_eval<T: Integer>(f: &Polynomial<T>, points: &[T]) -> Option<Vec<T>> {
+    let field = f.field();
+    if points.is_empty() {
+        return Some(vec![]);
+    }
+
+    let leaves: Vec<Polynomial<T>> = points
+        .iter()
+        .map(|x| Polynomial::new(field, vec![field.sub(T::zero(), x.clone()), T::one()]))
+        .collect();
+    let mut tree = vec![leaves];
+    while tree[tree.len() - 1].len() > 1 {
+        let next = tree[tree.len() - 1]
+            .chunks(2)
+            .map(|c| match c {
+                [a, b] => mul(a, b),
+                _ => Some(c[0].clone()),
+            })
+            .collect::<Option<Vec<_>>>()?;
+        tree.push(next);
+    }
+
+    let mut rems = vec![f.clone()];
+    for level in tree.iter().rev() {
+        rems = level
+            .iter()
+            .enumerate()
+            .map(|(i, m)| divrem(&rems[i / 2], m).map(|(_, r)| r))
+            .collect::<Option<Vec<_>>>()?;
+    }
+    Some(
+        rems.iter()
+            .map(|r| r.coeffs().first().cloned().unwrap_or_else(T::zero))
+            .collect(),
+    )
+}
+
+fn transform<T: Integer>(field: &Field<T>, values: &[T], w: T) -> Vec<T> {
+    let n = values.len();
+    let p = field.prime();
+    let mut a: Vec<T> = values
+        .iter()
+        .map(|v| arith::reduce(v.clone(), p.clone()))
+        .collect();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    // roots[s] = w^(n / 2^(s+1)), a primitive 2^(s+1)-th root.
+    let mut roots = vec![w];
+    while roots.len() < log2_exact(n) {
+        let r = roots[roots.len() - 1].clone();
+        roots.push(field.mul(r.clone(), r));
+    }
+    roots.reverse();
+
+    let mut len = 2;
+    for wl in roots {
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut wj = T::one();
+            for (u, v) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = field.mul(v.clone(), wj.clone());
+                *v = field.sub(u.clone(), t.clone());
+                *u = field.add(u.clone(), t);
+                wj = field.mul(wj, wl.clone());
+            }
+        }
+        len *= 2;
+    }
+    a
+}
+
+fn log2_exact(n: usize) -> usize {
+    assert!(n.is_power_of_two(), "NTT length must be a power of two");
+    n.trailing_zeros() as usize
+}
+
+/// Coefficients of f padded with zeros to n.
+fn padded<T: Integer>(f: &Polynomial<T>, n: usize) -> Vec<T> {
+    let mut c = f.coeffs().to_vec();
+    c.resize(n, T::zero());
+    c
+}
+
+/// f mod x^n.
+fn truncated<'a, T: Integer>(f: &Polynomial<'a, T>, n: usize) -> Polynomial<'a, T> {
+    let c = f.coeffs().iter().take(n).cloned().collect();
+    Polynomial::new(f.field(), c)
+}
+
+/// x^(n-1) * f(1/x), f of length at most n.
+fn reversed<'a, T: Integer>(f: &Polynomial<'a, T>, n: usize) -> Polynomial<'a, T> {
+    let mut c = padded(f, n);
+    c.reverse();
+    Polynomial::new(f.field(), c)
+}
@@ -0,0 +1,490 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::{arith, factor, Integer};
+
+use crate::field::Field;
+
+/// Polynomial over a prime field.
+///
+/// ```text
+/// coeffs[i] is the coefficient of x^i, the leading coefficient is never zero,
+/// the zero polynomial has no coefficients.
+/// The field is borrowed, mixing polynomials of different fields panics.
+/// ```
+#[derive(Debug, Clone)]
+pub struct Polynomial<'a, T> {
+    field: &'a Field<T>,
+    coeffs: Vec<T>,
+}
+
+impl<'a, T: Integer> PartialEq for Polynomial<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.field.prime() == other.field.prime() && self.coeffs == other.coeffs
+    }
+}
+
+impl<'a, T: Integer> Eq for Polynomial<'a, T> {}
+
+impl<'a, T: Integer> Polynomial<'a, T> {
+    /// Creates a polynomial from the coefficients, lowest degree first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     // x^2 + 2x + 3
+    ///     let f = poly::Polynomial::new(&fp, vec![3, 2, 1]);
+    ///     println!("{:?}", f.coeffs());
+    /// }
+    /// ```
+    pub fn new(field: &'a Field<T>, coeffs: Vec<T>) -> Self {
+        let p = field.prime();
+        let coeffs = coeffs
+            .into_iter()
+            .map(|c| arith::reduce(c, p.clone()))
+            .collect();
+        Polynomial { field, coeffs }.normalize()
+    }
+
+    /// Returns the zero polynomial.
+    pub fn zero(field: &'a Field<T>) -> Self {
+        Polynomial::new(field, vec![])
+    }
+
+    /// Returns the constant polynomial 1.
+    pub fn one(field: &'a Field<T>) -> Self {
+        Polynomial::new(field, vec![T::one()])
+    }
+
+    /// Returns c * x^degree.
+    pub fn monomial(field: &'a Field<T>, c: T, degree: usize) -> Self {
+        let mut coeffs = vec![T::zero(); degree];
+        coeffs.push(c);
+        Polynomial::new(field, coeffs)
+    }
+
+    /// Returns the polynomial x.
+    pub fn x(field: &'a Field<T>) -> Self {
+        Polynomial::monomial(field, T::one(), 1)
+    }
+
+    /// Returns the field of the coefficients.
+    pub fn field(&self) -> &'a Field<T> {
+        self.field
+    }
+
+    /// Returns the coefficients, lowest degree first.
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    /// Returns the degree, None for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Returns the leading coefficient, 0 for the zero polynomial.
+    pub fn leading_coefficient(&self) -> T {
+        self.coeffs.last().cloned().unwrap_or_else(T::zero)
+    }
+
+    /// Computes the sum of two polynomials.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     let f = poly::Polynomial::new(&fp, vec![3, 2, 1]);
+    ///     let g = poly::Polynomial::new(&fp, vec![36, 35]);
+    ///     println!("{:?}", f.add(&g).coeffs());
+    /// }
+    /// ```
+    pub fn add(&self, other: &Self) -> Self {
+        self.check(other);
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeffs = (0..n)
+            .map(|i| self.field.add(self.coeff(i), other.coeff(i)))
+            .collect();
+        self.with_coeffs(coeffs)
+    }
+
+    /// Computes the difference of two polynomials.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     let f = poly::Polynomial::new(&fp, vec![3, 2, 1]);
+    ///     let g = poly::Polynomial::new(&fp, vec![1, 2, 1]);
+    ///     println!("{:?}", f.sub(&g).coeffs());
+    /// }
+    /// ```
+    pub fn sub(&self, other: &Self) -> Self {
+        self.check(other);
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeffs = (0..n)
+            .map(|i| self.field.sub(self.coeff(i), other.coeff(i)))
+            .collect();
+        self.with_coeffs(coeffs)
+    }
+
+    /// Computes the product of two polynomials, schoolbook.
+    ///
+    /// ```text
+    /// (f * g)[k] = sum f[i] * g[k - i]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     let f = poly::Polynomial::new(&fp, vec![1, 1]);
+    ///     let g = poly::Polynomial::new(&fp, vec![36, 1]);
+    ///     println!("{:?}", f.mul(&g).coeffs());
+    /// }
+    /// ```
+    pub fn mul(&self, other: &Self) -> Self {
+        self.check(other);
+        if self.is_zero() || other.is_zero() {
+            return self.with_coeffs(vec![]);
+        }
+        let mut coeffs = vec![T::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                let t = self.field.mul(a.clone(), b.clone());
+                coeffs[i + j] = self.field.add(coeffs[i + j].clone(), t);
+            }
+        }
+        self.with_coeffs(coeffs)
+    }
+
+    /// Multiplies every coefficient by c.
+    pub fn scale(&self, c: T) -> Self {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .map(|a| self.field.mul(a.clone(), c.clone()))
+            .collect();
+        self.with_coeffs(coeffs)
+    }
+
+    /// Returns the polynomial scaled to leading coefficient 1, zero stays zero.
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        self.scale(self.inv(self.leading_coefficient()))
+    }
+
+    /// Computes the quotient and remainder of the long division.
+    ///
+    /// ```text
+    /// self = q * divisor + r, deg(r) < deg(divisor)
+    /// Panics if divisor is zero.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     let f = poly::Polynomial::new(&fp, vec![3, 2, 1]);
+    ///     let g = poly::Polynomial::new(&fp, vec![1, 1]);
+    ///     let (q, r) = f.divrem(&g);
+    ///     println!("{:?} {:?}", q.coeffs(), r.coeffs());
+    /// }
+    /// ```
+    pub fn divrem(&self, divisor: &Self) -> (Self, Self) {
+        self.check(divisor);
+        assert!(!divisor.is_zero(), "division by the zero polynomial");
+        let dn = divisor.coeffs.len();
+        if self.coeffs.len() < dn {
+            return (self.with_coeffs(vec![]), self.clone());
+        }
+
+        let lc_inv = self.inv(divisor.leading_coefficient());
+        let mut r = self.coeffs.clone();
+        let mut q = vec![T::zero(); r.len() - dn + 1];
+        for k in (0..q.len()).rev() {
+            let c = self.field.mul(r[k + dn - 1].clone(), lc_inv.clone());
+            for (i, d) in divisor.coeffs.iter().enumerate() {
+                let t = self.field.mul(c.clone(), d.clone());
+                r[k + i] = self.field.sub(r[k + i].clone(), t);
+            }
+            q[k] = c;
+        }
+        r.truncate(dn - 1);
+        (self.with_coeffs(q), self.with_coeffs(r))
+    }
+
+    /// Evaluates the polynomial at x with Horner's rule.
+    ///
+    /// ```text
+    /// f(x) = (...(f[n] * x + f[n-1]) * x + ...) * x + f[0]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     let f = poly::Polynomial::new(&fp, vec![3, 2, 1]);
+    ///     println!("{:?}", f.eval(5));
+    /// }
+    /// ```
+    pub fn eval(&self, x: T) -> T {
+        let x = arith::reduce(x, self.field.prime());
+        self.coeffs.iter().rev().fold(T::zero(), |acc, c| {
+            self.field.add(self.field.mul(acc, x.clone()), c.clone())
+        })
+    }
+
+    /// Computes the formal derivative.
+    ///
+    /// ```text
+    /// (sum f[i] * x^i)' = sum i * f[i] * x^(i-1)
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let mut i = T::zero();
+        let coeffs = self
+            .coeffs
+            .iter()
+            .skip(1)
+            .map(|c| {
+                i = self.field.add(i.clone(), T::one());
+                self.field.mul(i.clone(), c.clone())
+            })
+            .collect();
+        self.with_coeffs(coeffs)
+    }
+
+    /// Computes the monic greatest common divisor with Euclid's algorithm.
+    ///
+    /// ```text
+    /// gcd(f, 0) = f / lc(f)
+    /// gcd(f, g) = gcd(g, f mod g)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     // (x + 1)(x + 2), (x + 1)(x + 3)
+    ///     let f = poly::Polynomial::new(&fp, vec![2, 3, 1]);
+    ///     let g = poly::Polynomial::new(&fp, vec![3, 4, 1]);
+    ///     println!("{:?}", f.gcd(&g).coeffs());
+    /// }
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        self.check(other);
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, r) = a.divrem(&b);
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    /// Computes self^exponent mod modulus by square-and-multiply.
+    pub fn pow_mod(&self, exponent: T, modulus: &Self) -> Self {
+        let base = self.divrem(modulus).1;
+        let mut res = Polynomial::one(self.field).divrem(modulus).1;
+        for i in (0..exponent.bits()).rev() {
+            res = res.mul(&res).divrem(modulus).1;
+            if exponent.bit(i) {
+                res = res.mul(&base).divrem(modulus).1;
+            }
+        }
+        res
+    }
+
+    /// Computes the polynomial of least degree through the points.
+    ///
+    /// ```text
+    /// f(x) = sum y[i] * l[i](x)
+    /// l[i](x) = prod (x - x[j]) / (x[i] - x[j]), j != i
+    /// The x must be distinct modulo p.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     let f = poly::Polynomial::lagrange(&fp, &[(1, 6), (2, 11), (3, 18)]);
+    ///     println!("{:?}", f.coeffs());
+    /// }
+    /// ```
+    pub fn lagrange(field: &'a Field<T>, points: &[(T, T)]) -> Self {
+        let p = field.prime();
+        let mut res = Polynomial::zero(field);
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let mut basis = Polynomial::one(field);
+            let mut denom = T::one();
+            for (j, (xj, _)) in points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let d = field.sub(xi.clone(), xj.clone());
+                assert!(!d.is_zero(), "interpolation points must be distinct");
+                let xj = arith::reduce(xj.clone(), p.clone());
+                basis = basis.mul(&Polynomial::new(
+                    field,
+                    vec![field.sub(T::zero(), xj), T::one()],
+                ));
+                denom = field.mul(denom, d);
+            }
+            let c = field.mul(yi.clone(), field.inv(denom).unwrap());
+            res = res.add(&basis.scale(c));
+        }
+        res
+    }
+
+    /// Tests irreducibility with Rabin's test.
+    ///
+    /// ```text
+    /// f of degree n is irreducible over Fq if and only if:
+    /// x^(q^n) = x (mod f)
+    /// gcd(x^(q^(n/r)) - x, f) = 1 for every prime r dividing n
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     // x^2 - 2, 2 is not a square mod 37
+    ///     let f = poly::Polynomial::new(&fp, vec![35, 0, 1]);
+    ///     println!("{:?}", f.is_irreducible());
+    /// }
+    /// ```
+    pub fn is_irreducible(&self) -> bool {
+        let n = match self.degree() {
+            None | Some(0) => return false,
+            Some(1) => return true,
+            Some(n) => n,
+        };
+        let f = self.monic();
+        let x = Polynomial::x(self.field);
+        let divisors: Vec<usize> = factor::factorize(n as u64)
+            .into_iter()
+            .map(|(r, _)| n / r as usize)
+            .collect();
+
+        // h = x^(q^i) mod f
+        let mut h = x.clone();
+        for i in 1..=n {
+            h = h.pow_mod(self.field.prime(), &f);
+            if divisors.contains(&i) && f.gcd(&h.sub(&x)).degree() != Some(0) {
+                return false;
+            }
+        }
+        h == x.divrem(&f).1
+    }
+
+    /// Tests irreducibility with Ben-Or's test.
+    ///
+    /// ```text
+    /// f of degree n is irreducible over Fq if and only if
+    /// gcd(x^(q^i) - x, f) = 1 for every 1 <= i <= n/2,
+    /// x^(q^i) - x is the product of all monic irreducibles of degree dividing i.
+    /// Reducible polynomials usually stop early at a small factor.
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fields::field;
+    /// use fields::poly;
+    ///
+    /// fn main() {
+    ///     let fp = field::Field::new(37);
+    ///     let f = poly::Polynomial::new(&fp, vec![35, 0, 1]);
+    ///     println!("{:?}", f.is_irreducible_ben_or());
+    /// }
+    /// ```
+    pub fn is_irreducible_ben_or(&self) -> bool {
+        let n = match self.degree() {
+            None | Some(0) => return false,
+            Some(n) => n,
+        };
+        let f = self.monic();
+        let x = Polynomial::x(self.field);
+        let mut h = x.clone();
+        for _ in 1..=n / 2 {
+            h = h.pow_mod(self.field.prime(), &f);
+            if f.gcd(&h.sub(&x)).degree() != Some(0) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn coeff(&self, i: usize) -> T {
+        self.coeffs.get(i).cloned().unwrap_or_else(T::zero)
+    }
+
+    fn inv(&self, a: T) -> T {
+        self.field.inv(a).unwrap()
+    }
+
+    fn with_coeffs(&self, coeffs: Vec<T>) -> Self {
+        Polynomial {
+            field: self.field,
+            coeffs,
+        }
+        .normalize()
+    }
+
+    fn check(&self, other: &Self) {
+        assert!(
+            self.field.prime() == other.field.prime(),
+            "polynomials of different fields"
+        );
+    }
+
+    fn normalize(mut self) -> Self {
+        while matches!(self.coeffs.last(), Some(c) if c.is_zero()) {
+            self.coeffs.pop();
+        }
+        self
+    }
+}
@@ -0,0 +1,454 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::rng::Rng;
+use algebra::{arith, Integer};
+
+use crate::field::Field;
+use crate::poly::Polynomial;
+
+/// Splits f into square-free parts, f / lc(f) = prod a_i^i.
+///
+/// ```text
+/// Returns the monic, pairwise coprime, square-free a_i with their i.
+/// ```
+///
+/// ```text
+/// Algorithm, Yun's with the characteristic p fix:
+/// c = gcd(f, f'), w = f / c
+/// i = 1, while w != 1:
+///     y = gcd(w, c), a_i = w / y, w = y, c = c / y, i = i + 1
+/// Whatever is left in c has f' = 0, so c(x) = d(x^p) = d(x)^p,
+/// since a^p = a in Fp, recurse on d with multiplicities times p.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::poly::Polynomial;
+/// use fields::poly_factor;
+///
+/// fn main() {
+///     let fp = field::Field::new(37);
+///     // (x + 1)^2 * (x + 2)
+///     let f = Polynomial::new(&fp, vec![2, 5, 4, 1]);
+///     for (a, i) in poly_factor::square_free(&f) {
+///         println!("{:?}^{}", a.coeffs(), i);
+///     }
+/// }
+/// ```
+pub fn square_free<'a, T: Integer>(f: &Polynomial<'a, T>) -> Vec<(Polynomial<'a, T>, u32)> {
+    let field = f.field();
+    let one = Polynomial::one(field);
+    let mut res = Vec::new();
+    if f.degree().unwrap_or(0) == 0 {
+        return res;
+    }
+
+    let f = f.monic();
+    let mut c = f.gcd(&f.derivative());
+    let mut w = f.divrem(&c).0;
+    let mut i = 1;
+    while w != one {
+        let y = w.gcd(&c);
+        let a = w.divrem(&y).0;
+        if a != one {
+            res.push((a, i));
+        }
+        w = y.clone();
+        c = c.divrem(&y).0;
+        i += 1;
+    }
+
+    if c != one {
+        let p = field.prime().to_i64().unwrap() as usize;
+        let d: Vec<T> = c.coeffs().iter().step_by(p).cloned().collect();
+        for (a, i) in square_free(&Polynomial::new(field, d)) {
+            res.push((a, i * p as u32));
+        }
+    }
+    sort(&mut res);
+    res
+}
+
+/// Splits a square-free f into the products of its irreducible factors of equal degree.
+///
+/// ```text
+/// Returns (g_d, d), g_d the product of all monic irreducible factors of degree d.
+/// ```
+///
+/// ```text
+/// x^(p^d) - x is the product of all monic irreducibles of degree dividing d:
+/// d = 1, 2, ...:
+///     g_d = gcd(f, x^(p^d) - x), f = f / g_d
+/// stops once deg(f) < 2d, what is left is irreducible.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::poly::Polynomial;
+/// use fields::poly_factor;
+///
+/// fn main() {
+///     let fp = field::Field::new(37);
+///     // (x + 1) * (x^2 - 2)
+///     let f = Polynomial::new(&fp, vec![35, 35, 1, 1]);
+///     for (g, d) in poly_factor::distinct_degree(&f) {
+///         println!("{:?} {}", g.coeffs(), d);
+///     }
+/// }
+/// ```
+pub fn distinct_degree<'a, T: Integer>(f: &Polynomial<'a, T>) -> Vec<(Polynomial<'a, T>, usize)> {
+    let field = f.field();
+    let x = Polynomial::x(field);
+    let mut f = f.monic();
+    let mut h = x.clone();
+    let mut res = Vec::new();
+    let mut d = 1;
+    while f.degree().unwrap_or(0) >= 2 * d {
+        // h = x^(p^d) mod f
+        h = h.pow_mod(field.prime(), &f);
+        let g = f.gcd(&h.sub(&x));
+        if g.degree() != Some(0) {
+            f = f.divrem(&g).0;
+            h = h.divrem(&f).1;
+            res.push((g, d));
+        }
+        d += 1;
+    }
+    if let Some(n) = f.degree().filter(|&n| n > 0) {
+        res.push((f, n));
+    }
+    res
+}
+
+/// Splits a product of distinct monic irreducibles of degree d, Cantor-Zassenhaus.
+///
+/// ```text
+/// Returns the irreducible factors, sorted.
+/// ```
+///
+/// ```text
+/// Fp[x]/(f) = F(p^d) x ... x F(p^d), one copy per factor.
+/// a random, deg(a) < deg(f)
+/// p odd: b = a^((p^d - 1) / 2) is +-1 or 0 in every copy,
+///        gcd(f, b - 1) splits f with probability about 1/2
+/// p = 2: b = a + a^2 + ... + a^(2^(d-1)), the trace, is 0 or 1 in every copy,
+///        gcd(f, b) splits f with probability about 1/2
+/// recurse on both parts.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::rng::SplitMix64;
+/// use fields::field;
+/// use fields::poly::Polynomial;
+/// use fields::poly_factor;
+///
+/// fn main() {
+///     let fp = field::Field::new(37);
+///     // (x + 1) * (x + 2) * (x + 3)
+///     let f = Polynomial::new(&fp, vec![6, 11, 6, 1]);
+///     let mut rng = SplitMix64::new(1);
+///     for g in poly_factor::equal_degree(&f, 1, &mut rng) {
+///         println!("{:?}", g.coeffs());
+///     }
+/// }
+/// ```
+pub fn equal_degree<'a, T: Integer, R: Rng>(
+    f: &Polynomial<'a, T>,
+    d: usize,
+    rng: &mut R,
+) -> Vec<Polynomial<'a, T>> {
+    let mut res = Vec::new();
+    split(&f.monic(), d, rng, &mut res);
+    res.sort_by(|a, b| a.coeffs().cmp(b.coeffs()));
+    res
+}
+
+/// Factors f into monic irreducibles with Cantor-Zassenhaus.
+///
+/// ```text
+/// Returns (g, e) with f / lc(f) = prod g^e, sorted by degree.
+/// square_free -> distinct_degree -> equal_degree
+/// Expected polynomial time in deg(f) and log(p).
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::rng::SplitMix64;
+/// use fields::field;
+/// use fields::poly::Polynomial;
+/// use fields::poly_factor;
+///
+/// fn main() {
+///     let fp = field::Field::new(37);
+///     let f = Polynomial::new(&fp, vec![2, 5, 4, 1, 7, 1]);
+///     let mut rng = SplitMix64::new(1);
+///     for (g, e) in poly_factor::cantor_zassenhaus(&f, &mut rng) {
+///         println!("{:?}^{}", g.coeffs(), e);
+///     }
+/// }
+/// ```
+pub fn cantor_zassenhaus<'a, T: Integer, R: Rng>(
+    f: &Polynomial<'a, T>,
+    rng: &mut R,
+) -> Vec<(Polynomial<'a, T>, u32)> {
+    let mut res = Vec::new();
+    for (a, e) in square_free(f) {
+        for (g, d) in distinct_degree(&a) {
+            for h in equal_degree(&g, d, rng) {
+                res.push((h, e));
+            }
+        }
+    }
+    sort(&mut res);
+    res
+}
+
+/// Factors f into monic irreducibles with Berlekamp's algorithm.
+///
+/// ```text
+/// Returns (g, e) with f / lc(f) = prod g^e, sorted by degree.
+/// ```
+///
+/// ```text
+/// For a square-free f of degree n with k irreducible factors:
+/// the v with v^p = v (mod f) form a k-dimensional space over Fp,
+/// Q[i] = x^(i * p) mod f, v is in the kernel of Q - I.
+/// Every v is a constant s_j modulo each factor, so
+/// prod (v - s), s in Fp, is 0 (mod f) and gcd(u, v - s) splits the factors u.
+/// Deterministic, but tries every s in Fp, only for small p.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use fields::field;
+/// use fields::poly::Polynomial;
+/// use fields::poly_factor;
+///
+/// fn main() {
+///     let fp = field::Field::new(37);
+///     let f = Polynomial::new(&fp, vec![2, 5, 4, 1, 7, 1]);
+///     for (g, e) in poly_factor::berlekamp(&f) {
+///         println!("{:?}^{}", g.coeffs(), e);
+///     }
+/// }
+/// ```
+pub fn berlekamp<'a, T: Integer>(f: &Polynomial<'a, T>) -> Vec<(Polynomial<'a, T>, u32)> {
+    let mut res = Vec::new();
+    for (a, e) in square_free(f) {
+        for g in berlekamp_square_free(&a) {
+            res.push((g, e));
+        }
+    }
+    sort(&mut res);
+    res
+}
+
+/// Returns the distinct roots of f in Fp, sorted.
+///
+/// ```text
+/// g = gcd(f, x^p - x) is the product of x - r over all roots r,
+/// g is split into linear factors by equal_degree with d = 1.
+/// For f = x^2 - a these are the +-Field::sqrt(a), any degree works the same way.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::rng::SplitMix64;
+/// use fields::field;
+/// use fields::poly::Polynomial;
+/// use fields::poly_factor;
+///
+/// fn main() {
+///     let fp = field::Field::new(37);
+///     // x^3 - 2x + 1 = (x - 1)(x^2 + x - 1)
+///     let f = Polynomial::new(&fp, vec![1, 35, 0, 1]);
+///     let mut rng = SplitMix64::new(1);
+///     println!("{:?}", poly_factor::roots(&f, &mut rng));
+/// }
+/// ```
+pub fn roots<T: Integer, R: Rng>(f: &Polynomial<T>, rng: &mut R) -> Vec<T> {
+    let field = f.field();
+    if f.degree().unwrap_or(0) == 0 {
+        return vec![];
+    }
+    let f = f.monic();
+    let x = Polynomial::x(field);
+    let xp = x.pow_mod(field.prime(), &f);
+    let g = f.gcd(&xp.sub(&x));
+    let mut res: Vec<T> = equal_degree(&g, 1, rng)
+        .into_iter()
+        .map(|h| field.sub(T::zero(), h.coeffs()[0].clone()))
+        .collect();
+    res.sort();
+    res
+}
+
+fn split<'a, T: Integer, R: Rng>(
+    f: &Polynomial<'a, T>,
+    d: usize,
+    rng: &mut R,
+    res: &mut Vec<Polynomial<'a, T>>,
+) {
+    let n = match f.degree() {
+        Some(n) if n > d => n,
+        Some(n) if n > 0 => {
+            res.push(f.clone());
+            return;
+        }
+        _ => return,
+    };
+    let field = f.field();
+    let p = field.prime();
+    let one = Polynomial::one(field);
+
+    loop {
+        let a = random_poly(field, n, rng);
+        if a.degree().unwrap_or(0) == 0 {
+            continue;
+        }
+        let g = a.gcd(f);
+        let g = if g != one {
+            g
+        } else {
+            // t = a * a^p * ... * a^(p^(d-1)) or a + a^2 + ... + a^(2^(d-1))
+            let mut t = a.clone();
+            let mut ai = a;
+            for _ in 1..d {
+                ai = ai.pow_mod(p.clone(), f);
+                t = if p == T::two() {
+                    t.add(&ai)
+                } else {
+                    t.mul(&ai).divrem(f).1
+                };
+            }
+            if p == T::two() {
+                f.gcd(&t)
+            } else {
+                // (p^d - 1) / 2 = (1 + p + ... + p^(d-1)) * (p - 1) / 2
+                let b = t.pow_mod(p.clone() >> 1, f);
+                f.gcd(&b.sub(&one))
+            }
+        };
+        if g.degree().unwrap_or(0) > 0 && g.degree() != Some(n) {
+            split(&g, d, rng, res);
+            split(&f.divrem(&g).0, d, rng, res);
+            return;
+        }
+    }
+}
+
+fn berlekamp_square_free<'a, T: Integer>(f: &Polynomial<'a, T>) -> Vec<Polynomial<'a, T>> {
+    let field = f.field();
+    let n = f.degree().unwrap_or(0);
+    if n <= 1 {
+        return vec![f.clone()];
+    }
+
+    // (Q - I)^T, column i is x^(i * p) mod f - x^i.
+    let xp = Polynomial::x(field).pow_mod(field.prime(), f);
+    let mut m = vec![vec![T::zero(); n]; n];
+    let mut q = Polynomial::one(field);
+    for i in 0..n {
+        for (j, row) in m.iter_mut().enumerate() {
+            row[i] = q.coeffs().get(j).cloned().unwrap_or_else(T::zero);
+        }
+        m[i][i] = field.sub(m[i][i].clone(), T::one());
+        q = q.mul(&xp).divrem(f).1;
+    }
+
+    let basis = kernel(field, m);
+    let k = basis.len();
+    let mut factors = vec![f.clone()];
+    for v in basis.into_iter().map(|v| Polynomial::new(field, v)) {
+        if v.degree().unwrap_or(0) == 0 {
+            continue;
+        }
+        let mut s = T::zero();
+        while s < field.prime() && factors.len() < k {
+            let vs = v.sub(&Polynomial::new(field, vec![s.clone()]));
+            for i in 0..factors.len() {
+                let g = factors[i].gcd(&vs);
+                if g.degree().unwrap_or(0) > 0 && g.degree() != factors[i].degree() {
+                    let u = factors[i].divrem(&g).0;
+                    factors[i] = g;
+                    factors.push(u);
+                }
+            }
+            s = s + T::one();
+        }
+        if factors.len() == k {
+            break;
+        }
+    }
+    factors
+}
+
+/// Returns a basis of the kernel of m, by Gauss-Jordan elimination over Fp.
+fn kernel<T: Integer>(field: &Field<T>, mut m: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    let p = field.prime();
+    let (rows, cols) = (m.len(), m[0].len());
+    let mut pivots = Vec::new();
+    let mut r = 0;
+    for c in 0..cols {
+        let pivot = match (r..rows).find(|&i| !m[i][c].is_zero()) {
+            Some(i) => i,
+            None => continue,
+        };
+        m.swap(r, pivot);
+        let inv = arith::mod_inv(m[r][c].clone(), p.clone());
+        for v in m[r].iter_mut() {
+            *v = field.mul(v.clone(), inv.clone());
+        }
+        let pivot_row = m[r].clone();
+        for (i, row) in m.iter_mut().enumerate() {
+            if i == r || row[c].is_zero() {
+                continue;
+            }
+            let factor = row[c].clone();
+            for (v, pv) in row.iter_mut().zip(pivot_row.iter()) {
+                *v = field.sub(v.clone(), field.mul(factor.clone(), pv.clone()));
+            }
+        }
+        pivots.push(c);
+        r += 1;
+    }
+
+    // One basis vector per free column.
+    (0..cols)
+        .filter(|c| !pivots.contains(c))
+        .map(|free| {
+            let mut v = vec![T::zero(); cols];
+            v[free] = T::one();
+            for (i, &c) in pivots.iter().enumerate() {
+                v[c] = field.sub(T::zero(), m[i][free].clone());
+            }
+            v
+        })
+        .collect()
+}
+
+fn random_poly<'a, T: Integer, R: Rng>(
+    field: &'a Field<T>,
+    n: usize,
+    rng: &mut R,
+) -> Polynomial<'a, T> {
+    let p = field.prime().to_bigint().to_biguint().unwrap();
+    let coeffs = (0..n)
+        .map(|_| T::from_bigint(&rng.gen_below(&p).into()).unwrap())
+        .collect();
+    Polynomial::new(field, coeffs)
+}
+
+fn sort<T: Integer>(factors: &mut [(Polynomial<T>, u32)]) {
+    factors.sort_by(|(a, _), (b, _)| (a.degree(), a.coeffs()).cmp(&(b.degree(), b.coeffs())));
+}
@@ -0,0 +1,144 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use fields::element::{ExtensionElement, FieldElement};
+    use fields::field;
+    use fields::fp12::Fp12;
+    use fields::fp2::Fp2;
+    use fields::fp6::Fp6;
+
+    #[test]
+    fn fields_element_ops_test() {
+        let fp37 = field::Field::new(37);
+        let a = fp37.element(18);
+        let b = fp37.element(20);
+
+        assert_eq!((a + b).value(), 1);
+        assert_eq!((fp37.element(14) - fp37.element(19)).value(), 32);
+        assert_eq!((a * fp37.element(19)).value(), 9);
+        assert_eq!(a.pow(11).value(), 17);
+        assert_eq!((-a).value(), 19);
+        assert_eq!((-FieldElement::zero(&fp37)).value(), 0);
+
+        // Division is multiplication by the inverse.
+        let q = a / b;
+        assert_eq!(q * b, a);
+        assert_eq!(b.inverse().unwrap() * b, FieldElement::one(&fp37));
+        assert!(FieldElement::zero(&fp37).inverse().is_none());
+        assert_eq!(b.pow(-1), b.inverse().unwrap());
+
+        // Values are reduced.
+        assert_eq!(fp37.element(-1).value(), 36);
+        assert_eq!(fp37.element(40), fp37.element(3));
+        assert!(fp37.element(37).is_zero());
+    }
+
+    #[test]
+    fn fields_element_sqrt_test() {
+        let fp31 = field::Field::new(31);
+        for a in 0..31 {
+            let x = fp31.element(a);
+            match x.sqrt() {
+                Some(r) => assert_eq!(r * r, x),
+                None => assert_eq!(fp31.legendre_symbol(a).value(), -1),
+            }
+        }
+    }
+
+    #[test]
+    fn fields_element_display_test() {
+        let fp37 = field::Field::new(37);
+        assert_eq!(format!("{}", fp37.element(-5)), "32");
+        assert_eq!(fp37.element(5).field().prime(), 37);
+    }
+
+    #[test]
+    fn fields_element_reduction_test() {
+        let p = BigUint::from(65537u32);
+        let fp = field::Field::with_reduction(p.clone(), field::Reduction::Montgomery);
+        let a = fp.element(BigUint::from(12345u32));
+        let b = fp.element(BigUint::from(54321u32));
+        let c = &a * &b;
+        assert_eq!(c.value(), BigUint::from((12345u64 * 54321 % 65537) as u32));
+        assert_eq!(&c / &b, a);
+
+        // Borrowed and owned operands.
+        assert_eq!(&a + b.clone(), a.clone() + &b);
+        assert_eq!(&a - &b, -(b.clone() - a.clone()));
+        assert_eq!(-&a + &a, FieldElement::zero(&fp));
+        assert_eq!(a.pow(p - BigUint::from(1u32)), FieldElement::one(&fp));
+    }
+
+    #[test]
+    #[should_panic(expected = "elements of different fields")]
+    fn fields_element_mixed_test() {
+        let fp31 = field::Field::new(31);
+        let fp37 = field::Field::new(37);
+        let _ = fp31.element(1) + fp37.element(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn fields_element_div_zero_test() {
+        let fp37 = field::Field::new(37);
+        let _ = fp37.element(1) / fp37.element(0);
+    }
+
+    #[test]
+    fn fields_element_extension_ops_test() {
+        // The operators agree with the method API on every level of the tower.
+        let fp2 = Fp2::new(field::Field::new(103), -1);
+        let (a, b) = (fp2.element(3, 4), fp2.element(5, 6));
+        let (x, y) = (fp2.bind(a.clone()), fp2.bind(b.clone()));
+        assert_eq!((&x + &y).value(), fp2.add(&a, &b));
+        assert_eq!((&x - &y).value(), fp2.sub(&a, &b));
+        assert_eq!((&x * &y).value(), fp2.mul(&a, &b));
+        assert_eq!((-&x).value(), fp2.neg(&a));
+        assert_eq!(&x / &y * y.clone(), x);
+        assert_eq!(
+            x.pow(&BigUint::from(5u32)).value(),
+            fp2.exp(&a, &BigUint::from(5u32))
+        );
+        assert_eq!(format!("{}", x), format!("{}", a));
+        assert!(ExtensionElement::new(&fp2, fp2.zero()).inverse().is_none());
+
+        let fp6 = Fp6::with_default_non_residue(fp2.clone());
+        let (c, d) = (
+            fp6.element(a.clone(), b.clone(), fp2.one()),
+            fp6.element(b.clone(), fp2.zero(), a.clone()),
+        );
+        let (x, y) = (fp6.bind(c.clone()), fp6.bind(d.clone()));
+        assert_eq!((&x + &y).value(), fp6.add(&c, &d));
+        assert_eq!((&x - &y).value(), fp6.sub(&c, &d));
+        assert_eq!((&x * &y).value(), fp6.mul(&c, &d));
+        assert_eq!((-x.clone()).value(), fp6.neg(&c));
+        assert_eq!(x.clone() / y.clone() * y, x);
+
+        let fp12 = Fp12::new(fp6.clone());
+        let (e, f) = (fp12.element(c.clone(), d.clone()), fp12.element(d, c));
+        let (x, y) = (fp12.bind(e.clone()), fp12.bind(f.clone()));
+        assert_eq!((&x + &y).value(), fp12.add(&e, &f));
+        assert_eq!((&x - &y).value(), fp12.sub(&e, &f));
+        assert_eq!((x.clone() * &y).value(), fp12.mul(&e, &f));
+        assert_eq!((-&x).value(), fp12.neg(&e));
+        assert_eq!(&x / &y * &y, x);
+
+        // Towers built alike are the same extension.
+        let other = Fp2::new(field::Field::new(103), -1);
+        assert_eq!(
+            other.bind(a.clone()) + other.bind(b.clone()),
+            fp2.bind(a) + fp2.bind(b)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "elements of different extensions")]
+    fn fields_element_extension_mixed_test() {
+        let fp103 = Fp2::new(field::Field::new(103), -1);
+        let fp107 = Fp2::new(field::Field::new(107), -1);
+        let _ = fp103.bind(fp103.one()) * fp107.bind(fp107.one());
+    }
+}
@@ -3,6 +3,8 @@
 
 #[cfg(test)]
 pub mod tests {
+    use algebra::bigint::BigUint;
+    use algebra::symbol::Symbol;
     use fields::field;
 
     #[test]
@@ -37,6 +39,281 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn fields_wide_prime_test() {
+        // Larger than any i8 prime.
+        let fp = field::Field::new(65539u32);
+        assert_eq!(fp.add(65538, 2), 1);
+        assert_eq!(fp.sub(1, 2), 65538);
+        assert_eq!(fp.mul(256, 256), 65536);
+        assert_eq!(fp.prime(), 65539);
+    }
+
+    #[test]
+    fn fields_checked_test() {
+        assert_eq!(field::Field::new_checked(37).unwrap().prime(), 37);
+        assert!(field::Field::new_checked(35).is_none());
+        assert!(field::Field::new_checked(1).is_none());
+        assert!(field::Field::new_checked(-37).is_none());
+        // Carmichael number.
+        assert!(field::Field::new_checked(561u32).is_none());
+
+        let p256 = BigUint::from_str_radix(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            16,
+        )
+        .unwrap();
+        assert!(field::Field::new_checked(p256.clone()).is_some());
+        assert!(field::Field::new_checked(p256 - BigUint::from(2u32)).is_none());
+    }
+
+    #[test]
+    fn fields_reduction_test() {
+        let reductions = [
+            field::Reduction::Plain,
+            field::Reduction::Montgomery,
+            field::Reduction::Barrett,
+        ];
+
+        for reduction in reductions.iter() {
+            let fp37 = field::Field::with_reduction(37, *reduction);
+            assert_eq!(fp37.reduction(), *reduction);
+            assert_eq!(fp37.mul(18, 19), 9);
+            assert_eq!(fp37.mul(-1, 2), 35);
+            assert_eq!(fp37.exp(18, 11), 17);
+            assert_eq!(fp37.exp(18, 0), 1);
+            assert_eq!(fp37.exp(3, -1), 25);
+        }
+
+        // Same field code, three strategies on a 256-bit prime.
+        let p = BigUint::from_str_radix(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            16,
+        )
+        .unwrap();
+        let a = &p - &BigUint::from(12345u32);
+        let b = (BigUint::one() << 200) + BigUint::from(99u32);
+        let plain = field::Field::new(p.clone());
+        for reduction in reductions.iter() {
+            let fp = field::Field::with_reduction(p.clone(), *reduction);
+            assert_eq!(
+                fp.mul(a.clone(), b.clone()),
+                plain.mul(a.clone(), b.clone())
+            );
+            assert_eq!(
+                fp.exp(a.clone(), b.clone()),
+                plain.exp(a.clone(), b.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn fields_reduction_context_test() {
+        // The context is built once and shared by the clones of the field.
+        let p = (BigUint::one() << 127) - BigUint::one();
+        let fp = field::Field::with_reduction(p.clone(), field::Reduction::Montgomery);
+        let fq = fp.clone();
+        assert!(std::ptr::eq(
+            fp.montgomery().unwrap(),
+            fq.montgomery().unwrap()
+        ));
+        assert!(fp.barrett().is_none());
+        assert!(field::Field::new(p.clone()).montgomery().is_none());
+
+        // A chain of products stays equal to the one done in Montgomery form.
+        let values: Vec<BigUint> = (1..20u32)
+            .map(|i| (BigUint::one() << (6 * i as usize)) + BigUint::from(i))
+            .collect();
+        let ctx = fp.montgomery().unwrap();
+        let mut x = BigUint::one();
+        let mut xm = ctx.one();
+        for v in values.iter() {
+            x = fp.mul(x, v.clone());
+            xm = ctx.mont_mul(&xm, &ctx.to_mont(v));
+        }
+        assert_eq!(x, ctx.from_mont(&xm));
+
+        let fb = field::Field::with_reduction(p.clone(), field::Reduction::Barrett);
+        let br = fb.barrett().unwrap();
+        assert_eq!(br.modulus(), &p);
+        let mut y = BigUint::one();
+        let mut yb = BigUint::one();
+        for v in values.iter() {
+            y = fb.mul(y, v.clone());
+            yb = br.mul(&yb, v);
+        }
+        assert_eq!(y, yb);
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn fields_constant_time_test() {
+        let ct37 = field::Field::with_reduction(37, field::Reduction::ConstantTime);
+        assert_eq!(ct37.reduction(), field::Reduction::ConstantTime);
+        assert_eq!(ct37.add(18, 20), 1);
+        assert_eq!(ct37.sub(14, 19), 32);
+        assert_eq!(ct37.mul(18, 19), 9);
+        assert_eq!(ct37.mul(-1, 2), 35);
+        assert_eq!(ct37.exp(18, 11), 17);
+        assert_eq!(ct37.exp(18, 0), 1);
+        assert_eq!(ct37.exp(3, -1), 25);
+        assert_eq!(ct37.inv(14), Some(8));
+        assert_eq!(ct37.inv(0), None);
+        assert_eq!(ct37.sqrt(28), field::Field::new(37).sqrt(28));
+
+        // Same results as the fast backend, switched at runtime.
+        let mut fp = field::Field::new(1000000007i64);
+        let plain = fp.clone();
+        fp.set_reduction(field::Reduction::ConstantTime);
+        for &(a, b) in [(3, 5), (-7, 999999999), (123456789, 987654321), (0, 1)].iter() {
+            assert_eq!(fp.add(a, b), plain.add(a, b));
+            assert_eq!(fp.sub(a, b), plain.sub(a, b));
+            assert_eq!(fp.mul(a, b), plain.mul(a, b));
+            assert_eq!(fp.exp(a, b), plain.exp(a, b));
+            assert_eq!(fp.inv(a), plain.inv(a));
+        }
+        fp.set_reduction(field::Reduction::Plain);
+        assert_eq!(fp.reduction(), field::Reduction::Plain);
+    }
+
+    #[test]
+    fn fields_constant_time_sign_test() {
+        // Negative values and exponents take the word path, down to i64::MIN.
+        let p = 1000000007i64;
+        let fp = field::Field::with_reduction(p, field::Reduction::ConstantTime);
+        let plain = field::Field::new(p);
+        assert_eq!(fp.element(-p).value(), 0);
+        for &a in [-1, -p - 1, i64::MIN, i64::MIN + 1, i64::MAX].iter() {
+            assert_eq!(fp.element(a).value(), plain.element(a).value());
+            assert_eq!(fp.mul(a, 3), plain.mul(a, 3));
+            for &e in [-1, -2, -(p - 1), -p, i64::MIN, i64::MAX].iter() {
+                assert_eq!(fp.exp(a, e), plain.exp(a, e));
+            }
+        }
+        assert_eq!(fp.inv_fermat(-p), None);
+        assert_eq!(fp.inv_fermat(-1), Some(p - 1));
+        assert_eq!(fp.exp(0, i64::MAX), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero has no inverse")]
+    fn fields_constant_time_zero_inverse_test() {
+        let fp = field::Field::with_reduction(1000000007i64, field::Reduction::ConstantTime);
+        fp.exp(-1000000007, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "constant-time backend needs an element type within i64")]
+    fn fields_constant_time_wide_type_test() {
+        // The prime fits, but i128 values would leave the word path.
+        field::Field::with_reduction((1i128 << 61) - 1, field::Reduction::ConstantTime);
+    }
+
+    #[test]
+    #[should_panic(expected = "constant-time backend needs 1 < p < 2^63")]
+    fn fields_constant_time_wide_prime_test() {
+        // 2^64 - 59 is prime.
+        field::Field::with_reduction(18446744073709551557u64, field::Reduction::ConstantTime);
+    }
+
+    #[test]
+    fn fields_inv_test() {
+        let fp37 = field::Field::new(37);
+        assert_eq!(fp37.inv(14), Some(8));
+        assert_eq!(fp37.inv_fermat(14), Some(8));
+        assert_eq!(fp37.inv(-1), Some(36));
+        assert_eq!(fp37.inv(0), None);
+        assert_eq!(fp37.inv_fermat(37), None);
+        for a in 1..37 {
+            let x = fp37.inv(a).unwrap();
+            assert_eq!(fp37.mul(a, x), 1);
+            assert_eq!(fp37.inv_fermat(a), Some(x));
+        }
+
+        // Div test.
+        assert_eq!(fp37.div(4, 14), Some(32));
+        assert_eq!(fp37.div(4, 14), Some(algebra::arith::mod_div(4, 14, 37)));
+        assert_eq!(fp37.div(4, 0), None);
+
+        let p = BigUint::from_str_radix(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            16,
+        )
+        .unwrap();
+        let a = (BigUint::one() << 200) + BigUint::from(99u32);
+        for reduction in [field::Reduction::Montgomery, field::Reduction::Barrett].iter() {
+            let fp = field::Field::with_reduction(p.clone(), *reduction);
+            let x = fp.inv(a.clone()).unwrap();
+            assert_eq!(fp.inv_fermat(a.clone()), Some(x.clone()));
+            assert_eq!(fp.mul(a.clone(), x), BigUint::one());
+        }
+    }
+
+    #[test]
+    fn fields_batch_inv_test() {
+        let fp37 = field::Field::new(37);
+        let a: Vec<i32> = (1..37).collect();
+        let inv = fp37.batch_inv(&a).unwrap();
+        for (x, y) in a.iter().zip(inv.iter()) {
+            assert_eq!(fp37.inv(*x), Some(*y));
+        }
+        assert_eq!(fp37.batch_inv(&[14]), Some(vec![8]));
+        assert_eq!(fp37.batch_inv(&[]), Some(vec![]));
+        assert_eq!(fp37.batch_inv(&[1, 2, 74, 3]), None);
+    }
+
+    #[test]
+    fn fields_multiplicative_group_test() {
+        let fp37 = field::Field::new(37);
+        // 36 = 2^2 * 3^2, 2 is the least primitive root.
+        assert_eq!(fp37.primitive_root(), 2);
+        assert_eq!(fp37.multiplicative_order(2), Some(36));
+        assert_eq!(fp37.multiplicative_order(10), Some(3));
+        assert_eq!(fp37.multiplicative_order(36), Some(2));
+        assert_eq!(fp37.multiplicative_order(1), Some(1));
+        assert_eq!(fp37.multiplicative_order(37), None);
+
+        // Brute force over small primes.
+        for &p in [2i64, 3, 5, 7, 31, 37, 41, 97, 257].iter() {
+            let fp = field::Field::new(p);
+            let g = fp.primitive_root();
+            assert_eq!(fp.multiplicative_order(g), Some(p - 1));
+            for a in 1..p {
+                let n = (1..p).find(|&n| fp.exp(a, n) == 1).unwrap();
+                assert_eq!(fp.multiplicative_order(a), Some(n));
+                if n == p - 1 {
+                    assert!(g <= a);
+                }
+            }
+
+            for k in 1..13 {
+                let roots: Vec<i64> = (1..p).filter(|&x| fp.exp(x, k) == 1).collect();
+                assert_eq!(fp.roots_of_unity(k), roots);
+
+                let powers: Vec<i64> = (0..p).map(|x| fp.exp(x, k)).collect();
+                for a in 0..p {
+                    assert_eq!(fp.is_power_residue(a, k), powers.contains(&a));
+                }
+            }
+            // k = 2 agrees with the Legendre symbol.
+            for a in 1..p {
+                assert_eq!(
+                    fp.is_power_residue(a, 2),
+                    fp.legendre_symbol(a) == Symbol::One
+                );
+            }
+        }
+
+        // 30-bit prime, p - 1 = 2^3 * 3 * 5 * 7 * 11 * 13 * 17 * 19 * 23.
+        let fp = field::Field::new(892371481u64);
+        let g = fp.primitive_root();
+        assert_eq!(fp.multiplicative_order(g), Some(892371480));
+        assert_eq!(fp.roots_of_unity(23).len(), 23);
+        assert_eq!(fp.multiplicative_order(fp.exp(g, 23)), Some(892371480 / 23));
+        assert!(fp.is_power_residue(fp.exp(g, 46), 23));
+        assert!(!fp.is_power_residue(g, 23));
+    }
+
     #[test]
     fn fields_f12_test() {
         let p = 12;
@@ -95,6 +372,20 @@ pub mod tests {
         vec.clear();
     }
 
+    #[test]
+    fn fields_legendre_enum_test() {
+        let fp = field::Field::new(31);
+        assert_eq!(fp.legendre_symbol(0), Symbol::Zero);
+        assert_eq!(fp.legendre_symbol(31), Symbol::Zero);
+        assert_eq!(fp.legendre_symbol(28), Symbol::One);
+        assert_eq!(fp.legendre_symbol(3), Symbol::MinusOne);
+        assert_eq!(fp.legendre_symbol(-1), Symbol::MinusOne);
+
+        let fp2 = field::Field::new(2);
+        assert_eq!(fp2.legendre_symbol(0), Symbol::Zero);
+        assert_eq!(fp2.legendre_symbol(3), Symbol::One);
+    }
+
     #[test]
     fn fields_legendre_symbol_test() {
         let p = 31;
@@ -143,9 +434,8 @@ pub mod tests {
             let p = 31;
             let fp = field::Field::new(p);
             for i in 0..60 {
-                let sqrt = fp.sqrt(i);
-                if sqrt != None {
-                    println!("{},sqrt:{:?}", i, sqrt.unwrap());
+                if let Some(sqrt) = fp.sqrt(i) {
+                    println!("{},sqrt:{:?}", i, sqrt);
                 }
             }
             /*
@@ -188,4 +478,64 @@ pub mod tests {
             assert_eq!(fp.sqrt(33), Some(8));
         }
     }
+
+    #[test]
+    fn fields_sqrt_all_primes_test() {
+        // p = 2, 3 (mod 4), 5 (mod 8), 1 (mod 8)
+        for p in [2i64, 3, 5, 13, 17, 31, 37, 41, 73, 97, 113, 257, 65537].iter() {
+            let fp = field::Field::new(*p);
+            let mut squares = 0;
+            for a in 0..*p {
+                let aa = fp.mul(a, a);
+                let x = fp.sqrt(aa).unwrap();
+                assert_eq!(fp.mul(x, x), aa);
+                for y in [fp.sqrt_tonelli_shanks(aa), fp.sqrt_cipolla(aa)].iter() {
+                    assert_eq!(fp.mul(y.unwrap(), y.unwrap()), aa);
+                }
+
+                match fp.sqrt(a) {
+                    Some(x) => {
+                        assert_eq!(fp.mul(x, x), a);
+                        squares += 1;
+                    }
+                    None => {
+                        assert_eq!(fp.sqrt_tonelli_shanks(a), None);
+                        assert_eq!(fp.sqrt_cipolla(a), None);
+                    }
+                }
+            }
+            // 0 and (p - 1) / 2 non-zero squares.
+            assert_eq!(squares, if *p == 2 { 2 } else { (p + 1) / 2 });
+        }
+
+        let fp2 = field::Field::new(2);
+        assert_eq!(fp2.sqrt(0), Some(0));
+        assert_eq!(fp2.sqrt(1), Some(1));
+        assert_eq!(fp2.sqrt(3), Some(1));
+        // Every unit is a square modulo 2, there is no non-residue to search for.
+        assert_eq!(fp2.sqrt_tonelli_shanks(1), Some(1));
+        assert_eq!(fp2.sqrt_tonelli_shanks(4), Some(0));
+        assert_eq!(fp2.sqrt_cipolla(1), Some(1));
+        assert_eq!(fp2.sqrt_cipolla(-1), Some(1));
+    }
+
+    #[test]
+    fn fields_sqrt_wide_test() {
+        // 998244353 = 119 * 2^23 + 1, Tonelli-Shanks runs up to 23 rounds.
+        let fp = field::Field::with_reduction(998244353u64, field::Reduction::Montgomery);
+        for a in [2u64, 3, 5, 123456789, 998244352].iter() {
+            let aa = fp.mul(*a, *a);
+            let x = fp.sqrt(aa).unwrap();
+            assert_eq!(fp.mul(x, x), aa);
+            let y = fp.sqrt_cipolla(aa).unwrap();
+            assert_eq!(fp.mul(y, y), aa);
+        }
+        // 3 is the least non-residue.
+        assert_eq!(fp.sqrt(3), None);
+
+        // p = 2^127 - 1 = 3 (mod 4), i128 p + 1 would overflow.
+        let fp = field::Field::new(i128::MAX);
+        let x = fp.sqrt(4).unwrap();
+        assert!(x == 2 || x == i128::MAX - 2);
+    }
 }
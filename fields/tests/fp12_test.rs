@@ -0,0 +1,114 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use algebra::factor;
+    use fields::field;
+    use fields::fp12::{Fp12, Fp12Element};
+    use fields::fp2::Fp2;
+    use fields::fp6::Fp6;
+
+    fn fp12() -> Fp12<i64> {
+        let fp2 = Fp2::new(field::Field::new(103), -1);
+        Fp12::new(Fp6::with_default_non_residue(fp2))
+    }
+
+    fn samples(fp12: &Fp12<i64>) -> Vec<Fp12Element<i64>> {
+        let f6 = fp12.fp6();
+        let f = f6.fp2();
+        let a = f6.element(f.element(1, 2), f.element(3, 4), f.element(5, 6));
+        let b = f6.element(f.element(7, 0), f.element(0, 8), f.element(9, 10));
+        vec![
+            fp12.element(a.clone(), b.clone()),
+            fp12.element(b, f6.zero()),
+            fp12.element(f6.zero(), a),
+            fp12.element(f6.one(), f6.one()),
+        ]
+    }
+
+    #[test]
+    fn fields_fp12_arith_test() {
+        let fp12 = fp12();
+        let f6 = fp12.fp6();
+
+        // w^2 = v, w^6 = xi
+        let w = fp12.element(f6.zero(), f6.one());
+        let v = f6.element(f6.fp2().zero(), f6.fp2().one(), f6.fp2().zero());
+        assert_eq!(fp12.square(&w), fp12.element(v, f6.zero()));
+        let xi = fp12.element(f6.from_fp2(f6.non_residue()), f6.zero());
+        assert_eq!(fp12.exp(&w, &BigUint::from(6u32)), xi);
+
+        for a in samples(&fp12).iter() {
+            assert_eq!(fp12.square(a), fp12.mul(a, a));
+            assert_eq!(fp12.mul(a, &fp12.inv(a).unwrap()), fp12.one());
+            assert_eq!(fp12.mul(&fp12.div(&w, a).unwrap(), a), w);
+            assert!(fp12.is_zero(&fp12.add(a, &fp12.neg(a))));
+            for b in samples(&fp12).iter() {
+                assert_eq!(fp12.mul(a, b), fp12.mul(b, a));
+                assert_eq!(fp12.sub(&fp12.add(a, b), b), *a);
+            }
+        }
+        assert!(fp12.inv(&fp12.zero()).is_none());
+    }
+
+    #[test]
+    fn fields_fp12_frobenius_test() {
+        let fp12 = fp12();
+        let p = BigUint::from(103u32);
+        for a in samples(&fp12).iter() {
+            let mut b = a.clone();
+            for i in 1..=12 {
+                b = fp12.exp(&b, &p);
+                assert_eq!(fp12.frobenius(a, i), b);
+            }
+            assert_eq!(fp12.frobenius(a, 6), fp12.conjugate(a));
+            assert_eq!(fp12.frobenius(a, 12), *a);
+        }
+    }
+
+    #[test]
+    fn fields_fp12_cyclotomic_test() {
+        let fp12 = fp12();
+        let p2 = BigUint::from(103u32 * 103);
+        let phi12 = &p2 * &p2 - &p2 + BigUint::one();
+        for a in samples(&fp12).iter() {
+            let g = fp12.final_exponentiation_easy(a).unwrap();
+            assert_eq!(fp12.exp(&g, &phi12), fp12.one());
+            assert_eq!(fp12.cyclotomic_square(&g), fp12.square(&g));
+            assert_eq!(fp12.mul(&g, &fp12.conjugate(&g)), fp12.one());
+
+            let e = BigUint::from(123457u32);
+            assert_eq!(fp12.cyclotomic_exp(&g, &e), fp12.exp(&g, &e));
+        }
+        assert!(fp12.final_exponentiation_easy(&fp12.zero()).is_none());
+    }
+
+    #[test]
+    fn fields_fp12_final_exponentiation_test() {
+        let fp12 = fp12();
+        let p12 = BigUint::from(103u32).pow(12);
+        // 103^4 - 103^2 + 1 = 37 * 97 * 31357
+        let factors = factor::factorize(112540273u64);
+        assert_eq!(factors, vec![(37, 1), (97, 1), (31357, 1)]);
+        for (r, _) in factors.iter() {
+            let r = BigUint::from(*r);
+            let e = (&p12 - BigUint::one()) / &r;
+            for a in samples(&fp12).iter() {
+                let f = fp12.final_exponentiation(a, &r).unwrap();
+                assert_eq!(f, fp12.exp(a, &e));
+                // The result has order dividing r.
+                assert_eq!(fp12.exp(&f, &r), fp12.one());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "r must divide p^4 - p^2 + 1")]
+    fn fields_fp12_final_exponentiation_r_test() {
+        let fp12 = fp12();
+        let g = fp12.one();
+        fp12.final_exponentiation_hard(&g, &BigUint::from(5u32));
+    }
+}
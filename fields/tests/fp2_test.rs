@@ -0,0 +1,121 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use fields::field;
+    use fields::fp2::{Fp2, Fp2Element};
+
+    #[test]
+    fn fields_fp2_arith_test() {
+        let fp2 = Fp2::new(field::Field::new(31), -1);
+        let a = fp2.element(1, 2);
+        let b = fp2.element(3, 4);
+
+        assert_eq!(fp2.add(&a, &b), fp2.element(4, 6));
+        assert_eq!(fp2.sub(&a, &b), fp2.element(29, 29));
+        assert_eq!(fp2.neg(&a), fp2.element(30, 29));
+        // (1 + 2u)(3 + 4u) = 3 - 8 + 10u
+        assert_eq!(fp2.mul(&a, &b), fp2.element(-5, 10));
+        assert_eq!(fp2.square(&b), fp2.mul(&b, &b));
+        assert_eq!(fp2.mul_base(&a, 3), fp2.element(3, 6));
+        assert_eq!(fp2.element(32, -1), Fp2Element { c0: 1, c1: 30 });
+        assert_eq!(format!("{}", a), "1 + 2*u");
+
+        // u^2 = beta
+        let u = fp2.element(0, 1);
+        assert_eq!(fp2.square(&u), fp2.from_base(-1));
+
+        // Inverse and division.
+        assert_eq!(fp2.mul(&b, &fp2.inv(&b).unwrap()), fp2.one());
+        assert_eq!(fp2.mul(&fp2.div(&a, &b).unwrap(), &b), a);
+        assert!(fp2.inv(&fp2.zero()).is_none());
+        assert!(fp2.is_zero(&fp2.zero()));
+
+        // Conjugate and norm.
+        assert_eq!(fp2.conjugate(&b), fp2.element(3, -4));
+        assert_eq!(fp2.norm(&b), 25);
+        assert_eq!(fp2.mul(&b, &fp2.conjugate(&b)), fp2.from_base(fp2.norm(&b)));
+    }
+
+    #[test]
+    fn fields_fp2_non_residue_test() {
+        // 37 = 1 (mod 4), -1 is a square, 2 is the least non-residue.
+        let fp2 = Fp2::with_default_non_residue(field::Field::new(37));
+        assert_eq!(fp2.non_residue(), 2);
+        let fp2 = Fp2::with_default_non_residue(field::Field::new(31));
+        assert_eq!(fp2.non_residue(), 30);
+
+        // A non-residue other than the default.
+        let fp2 = Fp2::new(field::Field::new(37), 5);
+        let u = fp2.element(0, 1);
+        assert_eq!(fp2.square(&u), fp2.from_base(5));
+        for c0 in 0..37 {
+            for c1 in 0..37 {
+                let a = fp2.element(c0, c1);
+                assert_eq!(fp2.square(&a), fp2.mul(&a, &a));
+                if !fp2.is_zero(&a) {
+                    assert_eq!(fp2.mul(&a, &fp2.inv(&a).unwrap()), fp2.one());
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "u^2 must be a quadratic non-residue")]
+    fn fields_fp2_residue_test() {
+        Fp2::new(field::Field::new(37), -1);
+    }
+
+    #[test]
+    fn fields_fp2_frobenius_test() {
+        let fp2 = Fp2::new(field::Field::new(31), -1);
+        let p = BigUint::from(31u32);
+        for (c0, c1) in [(1, 2), (0, 1), (17, 30), (5, 0)].iter() {
+            let a = fp2.element(*c0, *c1);
+            assert_eq!(fp2.frobenius(&a, 1), fp2.exp(&a, &p));
+            assert_eq!(fp2.frobenius(&a, 2), a);
+            assert_eq!(fp2.frobenius(&a, 3), fp2.conjugate(&a));
+
+            // a^(p^2) = a, the multiplicative group has order p^2 - 1.
+            assert_eq!(fp2.exp(&a, &BigUint::from(31u32 * 31)), a);
+            assert_eq!(fp2.exp(&a, &BigUint::from(31u32 * 31 - 1)), fp2.one());
+        }
+    }
+
+    #[test]
+    fn fields_fp2_sqrt_test() {
+        for &(p, beta) in [(31i64, -1i64), (37, 2), (41, 3), (1009, 11)].iter() {
+            let fp2 = Fp2::new(field::Field::new(p), beta);
+            let mut squares = 0;
+            for c0 in 0..p.min(60) {
+                for c1 in 0..p.min(60) {
+                    let a = fp2.element(c0, c1);
+                    match fp2.sqrt(&a) {
+                        Some(r) => {
+                            assert_eq!(fp2.square(&r), a);
+                            assert!(fp2.is_square(&a));
+                            squares += 1;
+                        }
+                        None => assert!(!fp2.is_square(&a)),
+                    }
+                }
+            }
+            assert!(squares > 0);
+
+            // Every element of Fp is a square in Fp2.
+            for c0 in 0..p.min(60) {
+                let a = fp2.from_base(c0);
+                assert_eq!(fp2.square(&fp2.sqrt(&a).unwrap()), a);
+            }
+        }
+
+        // Half of Fp2^* are squares.
+        let fp2 = Fp2::new(field::Field::new(31), -1);
+        let squares = (0..31 * 31)
+            .filter(|i| fp2.sqrt(&fp2.element(i % 31, i / 31)).is_some())
+            .count();
+        assert_eq!(squares, (31 * 31 - 1) / 2 + 1);
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use fields::field;
+    use fields::fp2::Fp2;
+    use fields::fp6::{Fp6, Fp6Element};
+
+    fn fp6() -> Fp6<i64> {
+        Fp6::with_default_non_residue(Fp2::new(field::Field::new(103), -1))
+    }
+
+    fn samples(fp6: &Fp6<i64>) -> Vec<Fp6Element<i64>> {
+        let f = fp6.fp2();
+        vec![
+            fp6.element(f.element(1, 2), f.element(3, 4), f.element(5, 6)),
+            fp6.element(f.element(0, 0), f.element(1, 0), f.element(0, 0)),
+            fp6.element(f.element(102, 7), f.element(0, 55), f.element(13, 0)),
+            fp6.element(f.element(9, 0), f.element(0, 0), f.element(0, 0)),
+        ]
+    }
+
+    #[test]
+    fn fields_fp6_arith_test() {
+        let fp6 = fp6();
+        let f = fp6.fp2();
+        // xi = 2 + u, the least k + u neither a square nor a cube.
+        assert_eq!(fp6.non_residue(), f.element(2, 1));
+
+        // v^3 = xi
+        let v = fp6.element(f.zero(), f.one(), f.zero());
+        assert_eq!(
+            fp6.mul(&fp6.square(&v), &v),
+            fp6.from_fp2(fp6.non_residue())
+        );
+
+        for a in samples(&fp6).iter() {
+            assert_eq!(fp6.mul_by_v(a), fp6.mul(a, &v));
+            assert_eq!(fp6.sub(&fp6.add(a, &v), &v), *a);
+            assert!(fp6.is_zero(&fp6.add(a, &fp6.neg(a))));
+            assert_eq!(fp6.mul(a, &fp6.inv(a).unwrap()), fp6.one());
+            for b in samples(&fp6).iter() {
+                assert_eq!(fp6.mul(a, b), fp6.mul(b, a));
+                assert_eq!(
+                    fp6.mul(a, &fp6.add(b, &v)),
+                    fp6.add(&fp6.mul(a, b), &fp6.mul(a, &v))
+                );
+            }
+        }
+        assert!(fp6.inv(&fp6.zero()).is_none());
+
+        // The multiplicative group has order p^6 - 1.
+        let order = BigUint::from(103u64.pow(6) - 1);
+        for a in samples(&fp6).iter() {
+            assert_eq!(fp6.exp(a, &order), fp6.one());
+        }
+    }
+
+    #[test]
+    fn fields_fp6_frobenius_test() {
+        let fp6 = fp6();
+        let p = BigUint::from(103u32);
+        for a in samples(&fp6).iter() {
+            let mut b = a.clone();
+            for i in 1..=6 {
+                b = fp6.exp(&b, &p);
+                assert_eq!(fp6.frobenius(a, i), b);
+            }
+            assert_eq!(fp6.frobenius(a, 6), *a);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Fp6 needs a prime p = 1 (mod 3)")]
+    fn fields_fp6_prime_test() {
+        Fp6::with_default_non_residue(Fp2::new(field::Field::new(23), -1));
+    }
+
+    #[test]
+    #[should_panic(expected = "v^3 must not be a cube")]
+    fn fields_fp6_cube_test() {
+        let fp2 = Fp2::new(field::Field::new(103), -1);
+        let xi = fp2.element(8, 0);
+        Fp6::new(fp2, xi);
+    }
+}
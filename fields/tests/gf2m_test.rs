@@ -0,0 +1,143 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use fields::gf2m::GF2m;
+
+    #[test]
+    fn fields_gf2m_aes_test() {
+        let aes = GF2m::aes();
+        assert_eq!(aes.modulus(), 0x11b);
+        assert_eq!(aes.degree(), 8);
+
+        // FIPS-197 4.2
+        assert_eq!(aes.add(0x57, 0x83), 0xd4);
+        assert_eq!(aes.mul(0x57, 0x83), 0xc1);
+        assert_eq!(aes.mul(0x57, 0x13), 0xfe);
+        assert_eq!(aes.mul(0x57, 0x02), 0xae);
+        assert_eq!(aes.element(0x11b), 0);
+
+        // {53} and {ca} are inverses, the S-box entry of {53} starts from {ca}.
+        assert_eq!(aes.inv(0x53), Some(0xca));
+        assert_eq!(aes.div(0x01, 0xca), Some(0x53));
+        assert_eq!(aes.inv(0), None);
+
+        for a in 1..256 {
+            let b = aes.inv(a).unwrap();
+            assert_eq!(aes.mul(a, b), 1);
+            assert_eq!(b, aes.exp(a, 254));
+            assert_eq!(aes.square(a), aes.mul(a, a));
+            assert_eq!(aes.square(aes.sqrt(a)), a);
+        }
+    }
+
+    #[test]
+    fn fields_gf2m_inv_test() {
+        // Trinomials and pentanomials from SEC 2 / NIST, and small ones.
+        let fields = [
+            GF2m::new(2, &[1]),
+            GF2m::new(3, &[1]),
+            GF2m::new(5, &[2]),
+            GF2m::new(7, &[1]),
+            GF2m::new(16, &[5, 3, 1]),
+            GF2m::new(31, &[3]),
+            GF2m::new(63, &[1]),
+        ];
+        for f in fields.iter() {
+            let m = f.degree();
+            let order = (1u64 << m) - 1;
+            for &a in [1u64, 2, 3, 0x1234_5678_9abc_def0, u64::MAX].iter() {
+                let a = f.element(a);
+                if a == 0 {
+                    continue;
+                }
+                let b = f.inv(a).unwrap();
+                assert_eq!(f.mul(a, b), 1);
+                assert_eq!(f.exp(a, order), 1);
+                assert_eq!(b, f.exp(a, order - 1));
+            }
+        }
+    }
+
+    #[test]
+    fn fields_gf2m_trace_test() {
+        for f in [GF2m::new(5, &[2]), GF2m::aes(), GF2m::new(9, &[4])].iter() {
+            let n = 1u64 << f.degree();
+            let ones = (0..n).filter(|&a| f.trace(a) == 1).count() as u64;
+            assert_eq!(ones, n / 2);
+
+            for c in 0..n {
+                // Tr is linear and Tr(z^2 + z) = 0.
+                assert_eq!(f.trace(f.add(c, 3)), f.trace(c) ^ f.trace(3));
+                match f.solve_quadratic(c) {
+                    Some(z) => {
+                        assert_eq!(f.add(f.square(z), z), c);
+                        assert_eq!(f.trace(c), 0);
+                    }
+                    None => assert_eq!(f.trace(c), 1),
+                }
+                if f.degree() % 2 == 1 {
+                    let h = f.half_trace(c);
+                    assert_eq!(f.add(f.square(h), h), f.add(c, f.trace(c)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fields_gf2m_koblitz_test() {
+        // Koblitz curve y^2 + xy = x^3 + a * x^2 + 1 over GF(2^m), a in {0, 1}.
+        // #E(GF(2)) = 4 - a * 2, t = 3 - #E(GF(2)),
+        // #E(GF(2^m)) = 2^m + 1 - V(m), V(0) = 2, V(1) = t, V(k) = t * V(k-1) - 2 * V(k-2).
+        let f = GF2m::new(7, &[1]);
+        let n = 1u64 << f.degree();
+        for a in 0..2u64 {
+            let t = if a == 0 { -1i64 } else { 1 };
+            let (mut v0, mut v1) = (2i64, t);
+            for _ in 1..f.degree() {
+                let v2 = t * v1 - 2 * v0;
+                v0 = v1;
+                v1 = v2;
+            }
+            let expected = n as i64 + 1 - v1;
+
+            // Brute force.
+            let mut count = 1;
+            for x in 0..n {
+                for y in 0..n {
+                    let lhs = f.add(f.square(y), f.mul(x, y));
+                    let x2 = f.square(x);
+                    let rhs = f.add(f.add(f.mul(x2, x), f.mul(a, x2)), 1);
+                    if lhs == rhs {
+                        count += 1;
+                    }
+                }
+            }
+            assert_eq!(count, expected);
+
+            // Via the trace: x != 0, substitute y = x * z, z^2 + z = x + a + 1 / x^2.
+            let mut count = 2;
+            for x in 1..n {
+                let c = f.add(f.add(x, a), f.inv(f.square(x)).unwrap());
+                if f.solve_quadratic(c).is_some() {
+                    count += 2;
+                }
+            }
+            assert_eq!(count, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "reduction polynomial must be irreducible")]
+    fn fields_gf2m_reducible_test() {
+        // x^4 + x^2 + 1 = (x^2 + x + 1)^2
+        GF2m::new(4, &[2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "reduction polynomial must be a trinomial or pentanomial")]
+    fn fields_gf2m_terms_test() {
+        GF2m::new(8, &[4, 3]);
+    }
+}
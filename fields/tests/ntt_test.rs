@@ -0,0 +1,148 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::rng::{Rng, SplitMix64};
+    use fields::field;
+    use fields::ntt;
+    use fields::poly::Polynomial;
+
+    const P: i64 = 998244353;
+
+    fn random_poly<'a>(
+        fp: &'a field::Field<i64>,
+        rng: &mut SplitMix64,
+        len: usize,
+    ) -> Polynomial<'a, i64> {
+        let c = (0..len)
+            .map(|_| (rng.next_u64() % P as u64) as i64)
+            .collect();
+        Polynomial::new(fp, c)
+    }
+
+    #[test]
+    fn fields_ntt_root_test() {
+        let fp = field::Field::new(P);
+        assert_eq!(ntt::two_adicity(&fp), 23);
+        for k in 0..=23 {
+            let w = ntt::root_of_unity(&fp, k).unwrap();
+            assert_eq!(fp.exp(w, 1 << k), 1);
+            if k > 0 {
+                assert_eq!(fp.exp(w, 1 << (k - 1)), P - 1);
+            }
+        }
+        assert!(ntt::root_of_unity(&fp, 24).is_none());
+
+        // 37 - 1 = 9 * 2^2
+        let fp37 = field::Field::new(37);
+        assert_eq!(ntt::two_adicity(&fp37), 2);
+        assert_eq!(
+            ntt::root_of_unity(&fp37, 2).map(|w| fp37.mul(w, w)),
+            Some(36)
+        );
+        assert!(ntt::root_of_unity(&fp37, 3).is_none());
+        assert_eq!(ntt::root_of_unity(&fp37, 0), Some(1));
+
+        let fp2 = field::Field::new(2);
+        assert_eq!(ntt::two_adicity(&fp2), 0);
+        assert!(ntt::root_of_unity(&fp2, 1).is_none());
+    }
+
+    #[test]
+    fn fields_ntt_transform_test() {
+        // Against the definition A[j] = sum a[i] * w^(i * j).
+        let fp = field::Field::new(17);
+        let a = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let w = ntt::root_of_unity(&fp, 3).unwrap();
+        let expected: Vec<i32> = (0..8)
+            .map(|j| {
+                (0..8).fold(0, |acc, i| {
+                    fp.add(acc, fp.mul(a[i as usize], fp.exp(w, i * j)))
+                })
+            })
+            .collect();
+        let t = ntt::ntt(&fp, &a).unwrap();
+        assert_eq!(t, expected);
+        assert_eq!(ntt::intt(&fp, &t).unwrap(), a);
+        assert_eq!(ntt::ntt(&fp, &[5]), Some(vec![5]));
+
+        // Round trip.
+        let fp = field::Field::new(P);
+        let mut rng = SplitMix64::new(1);
+        let a: Vec<i64> = (0..1024)
+            .map(|_| (rng.next_u64() % P as u64) as i64)
+            .collect();
+        assert_eq!(ntt::intt(&fp, &ntt::ntt(&fp, &a).unwrap()).unwrap(), a);
+
+        // No 16th root of unity in F37.
+        assert!(ntt::ntt(&field::Field::new(37), &[0; 16]).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn fields_ntt_length_test() {
+        ntt::ntt(&field::Field::new(17), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn fields_ntt_mul_test() {
+        let fp = field::Field::new(P);
+        let mut rng = SplitMix64::new(2);
+        for &(n, m) in [(1, 1), (3, 5), (64, 64), (100, 37), (300, 257)].iter() {
+            let f = random_poly(&fp, &mut rng, n);
+            let g = random_poly(&fp, &mut rng, m);
+            assert_eq!(ntt::mul(&f, &g).unwrap(), f.mul(&g));
+        }
+        let f = random_poly(&fp, &mut rng, 10);
+        assert!(ntt::mul(&f, &Polynomial::zero(&fp)).unwrap().is_zero());
+
+        // F37 only has 4th roots of unity, degree 6 products need 8th.
+        let fp37 = field::Field::new(37);
+        let a = Polynomial::new(&fp37, vec![1, 1]);
+        let b = Polynomial::new(&fp37, vec![1, 1, 1]);
+        assert_eq!(ntt::mul(&a, &b).unwrap(), a.mul(&b));
+        assert!(ntt::mul(&b, &b.mul(&b)).is_none());
+    }
+
+    #[test]
+    fn fields_ntt_divrem_test() {
+        let fp = field::Field::new(P);
+        let mut rng = SplitMix64::new(3);
+        for &(n, m) in [(5, 1), (5, 5), (100, 30), (257, 128), (20, 40)].iter() {
+            let f = random_poly(&fp, &mut rng, n);
+            let g = random_poly(&fp, &mut rng, m);
+            assert_eq!(ntt::divrem(&f, &g).unwrap(), f.divrem(&g));
+        }
+    }
+
+    #[test]
+    fn fields_ntt_eval_test() {
+        let fp = field::Field::new(P);
+        let mut rng = SplitMix64::new(4);
+        let f = random_poly(&fp, &mut rng, 100);
+
+        // On the roots of unity, also with folding.
+        for &k in [3, 7, 8].iter() {
+            let w = ntt::root_of_unity(&fp, k).unwrap();
+            let values = ntt::evaluate_domain(&f, k).unwrap();
+            for (j, v) in values.iter().enumerate() {
+                assert_eq!(*v, f.eval(fp.exp(w, j as i64)));
+            }
+        }
+
+        // On arbitrary points.
+        let points: Vec<i64> = (0..77)
+            .map(|_| (rng.next_u64() % P as u64) as i64)
+            .collect();
+        let values = ntt::multi_eval(&f, &points).unwrap();
+        for (x, v) in points.iter().zip(values.iter()) {
+            assert_eq!(*v, f.eval(*x));
+        }
+        assert_eq!(ntt::multi_eval(&f, &[]), Some(vec![]));
+        assert_eq!(
+            ntt::multi_eval(&Polynomial::zero(&fp), &[1, 2]),
+            Some(vec![0, 0])
+        );
+    }
+}
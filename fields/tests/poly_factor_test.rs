@@ -0,0 +1,164 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::rng::{Rng, SplitMix64};
+    use fields::field;
+    use fields::poly::Polynomial;
+    use fields::poly_factor;
+
+    fn product<'a>(
+        fp: &'a field::Field<i64>,
+        factors: &[(Polynomial<'a, i64>, u32)],
+    ) -> Polynomial<'a, i64> {
+        factors.iter().fold(Polynomial::one(fp), |acc, (g, e)| {
+            (0..*e).fold(acc, |acc, _| acc.mul(g))
+        })
+    }
+
+    fn check(fp: &field::Field<i64>, f: &Polynomial<i64>, factors: &[(Polynomial<i64>, u32)]) {
+        assert_eq!(product(fp, factors), f.monic());
+        for (g, _) in factors.iter() {
+            assert!(g.is_irreducible());
+            assert_eq!(g.leading_coefficient(), 1);
+        }
+    }
+
+    #[test]
+    fn fields_poly_factor_square_free_test() {
+        let fp = field::Field::new(37);
+        // (x + 1)^2 * (x + 2)
+        let f = Polynomial::new(&fp, vec![2, 5, 4, 1]);
+        let sf = poly_factor::square_free(&f.scale(5));
+        assert_eq!(sf.len(), 2);
+        assert_eq!(sf[0], (Polynomial::new(&fp, vec![1, 1]), 2));
+        assert_eq!(sf[1], (Polynomial::new(&fp, vec![2, 1]), 1));
+
+        // Multiplicities p and p + 1 in F3: x^3 * (x + 1)^4
+        let fp3 = field::Field::new(3);
+        let x = Polynomial::x(&fp3);
+        let x1 = Polynomial::new(&fp3, vec![1, 1]);
+        let f = x.mul(&x).mul(&x).mul(&x1).mul(&x1).mul(&x1).mul(&x1);
+        let sf = poly_factor::square_free(&f);
+        assert_eq!(sf, vec![(x.clone(), 3), (x1.clone(), 4)]);
+
+        // x^9 + 1 = (x + 1)^9 in F3, f' = 0.
+        let f = Polynomial::monomial(&fp3, 1, 9).add(&Polynomial::one(&fp3));
+        assert_eq!(poly_factor::square_free(&f), vec![(x1, 9)]);
+
+        assert!(poly_factor::square_free(&Polynomial::one(&fp)).is_empty());
+    }
+
+    #[test]
+    fn fields_poly_factor_distinct_degree_test() {
+        let fp = field::Field::new(37);
+        // (x + 1) * (x + 3) * (x^2 - 2) * (x^2 - 5)
+        let lin = Polynomial::new(&fp, vec![3, 4, 1]);
+        let quad = Polynomial::new(&fp, vec![35, 0, 1]).mul(&Polynomial::new(&fp, vec![32, 0, 1]));
+        let dd = poly_factor::distinct_degree(&lin.mul(&quad));
+        assert_eq!(dd, vec![(lin, 1), (quad, 2)]);
+
+        // x^3 - 2, 2 is not a cube mod 37.
+        let f = Polynomial::new(&fp, vec![35, 0, 0, 1]);
+        assert!(f.is_irreducible());
+        assert_eq!(poly_factor::distinct_degree(&f), vec![(f, 3)]);
+    }
+
+    #[test]
+    fn fields_poly_factor_equal_degree_test() {
+        let fp = field::Field::new(37);
+        let mut rng = SplitMix64::new(1);
+        // (x + 1) * (x + 2) * (x + 3)
+        let f = Polynomial::new(&fp, vec![6, 11, 6, 1]);
+        let g = poly_factor::equal_degree(&f, 1, &mut rng);
+        assert_eq!(
+            g,
+            vec![
+                Polynomial::new(&fp, vec![1, 1]),
+                Polynomial::new(&fp, vec![2, 1]),
+                Polynomial::new(&fp, vec![3, 1]),
+            ]
+        );
+
+        // (x^2 - 2) * (x^2 - 5)
+        let a = Polynomial::new(&fp, vec![32, 0, 1]);
+        let b = Polynomial::new(&fp, vec![35, 0, 1]);
+        assert_eq!(
+            poly_factor::equal_degree(&a.mul(&b), 2, &mut rng),
+            vec![a, b]
+        );
+
+        // Over F2: (x^2 + x + 1) * (x^3 + x + 1) * (x^3 + x^2 + 1), degree 3 part.
+        let f2 = field::Field::new(2);
+        let c1 = Polynomial::new(&f2, vec![1, 1, 0, 1]);
+        let c2 = Polynomial::new(&f2, vec![1, 0, 1, 1]);
+        let g = poly_factor::equal_degree(&c1.mul(&c2), 3, &mut rng);
+        assert_eq!(g, vec![c2.clone(), c1.clone()]);
+        let f = Polynomial::new(&f2, vec![1, 1, 1]).mul(&c1).mul(&c2);
+        let cz = poly_factor::cantor_zassenhaus(&f, &mut rng);
+        assert_eq!(cz.len(), 3);
+        assert_eq!(poly_factor::berlekamp(&f), cz);
+    }
+
+    #[test]
+    fn fields_poly_factor_full_test() {
+        let mut rng = SplitMix64::new(2);
+        for &p in [2i64, 3, 5, 37, 101].iter() {
+            let fp = field::Field::new(p);
+            for n in 1..12 {
+                let coeffs: Vec<i64> = (0..n).map(|_| (rng.next_u64() % p as u64) as i64).collect();
+                let mut f = Polynomial::new(&fp, coeffs);
+                // Force repeated factors.
+                f = f.mul(&f.derivative().add(&Polynomial::x(&fp))).mul(&f);
+                if f.degree().unwrap_or(0) == 0 {
+                    continue;
+                }
+                let cz = poly_factor::cantor_zassenhaus(&f, &mut rng);
+                check(&fp, &f, &cz);
+                let bk = poly_factor::berlekamp(&f);
+                check(&fp, &f, &bk);
+                assert_eq!(cz, bk);
+            }
+        }
+    }
+
+    #[test]
+    fn fields_poly_factor_roots_test() {
+        let fp = field::Field::new(37);
+        let mut rng = SplitMix64::new(3);
+        // (x - 1)(x - 5)(x - 30)^2 (x^2 - 2)
+        let f = [1, 5, 30, 30]
+            .iter()
+            .fold(Polynomial::new(&fp, vec![35, 0, 1]), |acc, r| {
+                acc.mul(&Polynomial::new(&fp, vec![-r, 1]))
+            });
+        assert_eq!(poly_factor::roots(&f, &mut rng), vec![1, 5, 30]);
+        assert!(poly_factor::roots(&Polynomial::new(&fp, vec![35, 0, 1]), &mut rng).is_empty());
+        assert_eq!(poly_factor::roots(&Polynomial::x(&fp), &mut rng), vec![0]);
+        assert!(poly_factor::roots(&Polynomial::one(&fp), &mut rng).is_empty());
+
+        // Same as Field::sqrt.
+        for a in 1..37 {
+            let f = Polynomial::new(&fp, vec![-a, 0, 1]);
+            let r = poly_factor::roots(&f, &mut rng);
+            match fp.sqrt(a) {
+                Some(s) => {
+                    let mut expected = vec![s, 37 - s];
+                    expected.sort();
+                    assert_eq!(r, expected);
+                }
+                None => assert!(r.is_empty()),
+            }
+        }
+
+        // Points on y^2 = x^3 + 7 over F_1009 with y = 3: x^3 + 7 - 9 = 0.
+        let fp = field::Field::new(1009);
+        let f = Polynomial::new(&fp, vec![-2, 0, 0, 1]);
+        for x in poly_factor::roots(&f, &mut rng) {
+            assert_eq!(f.eval(x), 0);
+        }
+        let brute: Vec<i64> = (0..1009).filter(|&x| f.eval(x) == 0).collect();
+        assert_eq!(poly_factor::roots(&f, &mut rng), brute);
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use algebra::bigint::BigUint;
+    use fields::field;
+    use fields::poly::Polynomial;
+
+    #[test]
+    fn fields_poly_arith_test() {
+        let fp = field::Field::new(37);
+        let f = Polynomial::new(&fp, vec![3, 2, 1]);
+        let g = Polynomial::new(&fp, vec![36, 35]);
+
+        // Add/Sub test.
+        assert_eq!(f.add(&g).coeffs(), &[2, 0, 1]);
+        assert_eq!(f.sub(&f), Polynomial::zero(&fp));
+        assert_eq!(f.sub(&f).degree(), None);
+        assert_eq!(Polynomial::new(&fp, vec![1, 2, 1]).sub(&f).coeffs(), &[35]);
+
+        // Coefficients are reduced and trimmed.
+        let h = Polynomial::new(&fp, vec![-1, 38, 0, 37]);
+        assert_eq!(h.coeffs(), &[36, 1]);
+        assert_eq!(h.degree(), Some(1));
+        assert_eq!(h.leading_coefficient(), 1);
+
+        // Mul test, (x + 1)(x - 1) = x^2 - 1.
+        let a = Polynomial::new(&fp, vec![1, 1]);
+        let b = Polynomial::new(&fp, vec![36, 1]);
+        assert_eq!(a.mul(&b).coeffs(), &[36, 0, 1]);
+        assert!(a.mul(&Polynomial::zero(&fp)).is_zero());
+
+        // Monic test.
+        assert_eq!(Polynomial::new(&fp, vec![4, 2]).monic().coeffs(), &[2, 1]);
+        assert_eq!(Polynomial::x(&fp), Polynomial::monomial(&fp, 1, 1));
+    }
+
+    #[test]
+    fn fields_poly_divrem_test() {
+        let fp = field::Field::new(37);
+        let f = Polynomial::new(&fp, vec![5, 0, 3, 2, 7]);
+        let g = Polynomial::new(&fp, vec![1, 4, 3]);
+        let (q, r) = f.divrem(&g);
+        assert!(r.degree().unwrap() < g.degree().unwrap());
+        assert_eq!(q.mul(&g).add(&r), f);
+
+        // x^2 + 2x + 3 = (x + 1)(x + 1) + 2
+        let (q, r) = Polynomial::new(&fp, vec![3, 2, 1]).divrem(&Polynomial::new(&fp, vec![1, 1]));
+        assert_eq!(q.coeffs(), &[1, 1]);
+        assert_eq!(r.coeffs(), &[2]);
+
+        // Lower degree than the divisor.
+        let (q, r) = g.divrem(&f);
+        assert!(q.is_zero());
+        assert_eq!(r, g);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fields_poly_divrem_zero_test() {
+        let fp = field::Field::new(37);
+        Polynomial::one(&fp).divrem(&Polynomial::zero(&fp));
+    }
+
+    #[test]
+    #[should_panic(expected = "polynomials of different fields")]
+    fn fields_poly_different_fields_test() {
+        let fp = field::Field::new(37);
+        let fq = field::Field::new(41);
+        Polynomial::x(&fp).add(&Polynomial::x(&fq));
+    }
+
+    #[test]
+    fn fields_poly_eval_test() {
+        let fp = field::Field::new(37);
+        let f = Polynomial::new(&fp, vec![3, 2, 1]);
+        assert_eq!(f.eval(0), 3);
+        assert_eq!(f.eval(5), 1);
+        assert_eq!(f.eval(-1), 2);
+        assert_eq!(Polynomial::zero(&fp).eval(5), 0);
+
+        // Derivative test, (x^3 + 3x^2 + 5x + 7)' = 3x^2 + 6x + 5
+        let g = Polynomial::new(&fp, vec![7, 5, 3, 1]);
+        assert_eq!(g.derivative().coeffs(), &[5, 6, 3]);
+        assert!(Polynomial::new(&fp, vec![7]).derivative().is_zero());
+
+        // (x^5)' = 5x^4 = 0 in F5.
+        let fp5 = field::Field::new(5);
+        assert!(Polynomial::monomial(&fp5, 1, 5).derivative().is_zero());
+    }
+
+    #[test]
+    fn fields_poly_lagrange_test() {
+        let fp = field::Field::new(37);
+        // x^2 + 2x + 3
+        let f = Polynomial::lagrange(&fp, &[(1, 6), (2, 11), (3, 18)]);
+        assert_eq!(f.coeffs(), &[3, 2, 1]);
+
+        let g = Polynomial::new(&fp, vec![11, 0, 4, 29, 1]);
+        let points: Vec<(i64, i64)> = (10..15).map(|x| (x, g.eval(x))).collect();
+        assert_eq!(Polynomial::lagrange(&fp, &points), g);
+
+        // Recover the secret, f(0).
+        assert_eq!(Polynomial::lagrange(&fp, &points).eval(0), 11);
+
+        // Big field.
+        let p = BigUint::from_str_radix(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            16,
+        )
+        .unwrap();
+        let fp = field::Field::new(p);
+        let one = BigUint::one();
+        let two = BigUint::from(2u32);
+        let three = BigUint::from(3u32);
+        let f = Polynomial::new(&fp, vec![three.clone(), two.clone(), one.clone()]);
+        let points = vec![
+            (one.clone(), f.eval(one)),
+            (two.clone(), f.eval(two)),
+            (three.clone(), f.eval(three)),
+        ];
+        assert_eq!(Polynomial::lagrange(&fp, &points), f);
+    }
+
+    #[test]
+    fn fields_poly_gcd_test() {
+        let fp = field::Field::new(37);
+        // (x + 1)(x + 2), (x + 1)(x + 3)
+        let f = Polynomial::new(&fp, vec![2, 3, 1]);
+        let g = Polynomial::new(&fp, vec![3, 4, 1]);
+        assert_eq!(f.gcd(&g).coeffs(), &[1, 1]);
+        assert_eq!(f.scale(5).gcd(&g.scale(7)).coeffs(), &[1, 1]);
+        assert_eq!(f.gcd(&Polynomial::zero(&fp)), f);
+        assert_eq!(
+            f.gcd(&Polynomial::new(&fp, vec![5, 1])),
+            Polynomial::one(&fp)
+        );
+
+        // Repeated roots share a factor with the derivative.
+        let h = f.mul(&f);
+        assert_eq!(h.gcd(&h.derivative()), f);
+    }
+
+    #[test]
+    fn fields_poly_irreducible_test() {
+        let fp = field::Field::new(37);
+        // x^2 - 2, 2 is not a square mod 37.
+        let f = Polynomial::new(&fp, vec![35, 0, 1]);
+        assert!(f.is_irreducible());
+        assert!(f.is_irreducible_ben_or());
+
+        // x^2 - 3 = (x - 15)(x + 15)
+        let g = Polynomial::new(&fp, vec![34, 0, 1]);
+        assert!(!g.is_irreducible());
+        assert!(!g.is_irreducible_ben_or());
+
+        // Product of two irreducible quadratics has no roots but is reducible.
+        let h = f.mul(&Polynomial::new(&fp, vec![32, 0, 1]));
+        assert!((0..37).all(|x| h.eval(x) != 0));
+        assert!(!h.is_irreducible());
+        assert!(!h.is_irreducible_ben_or());
+
+        assert!(Polynomial::new(&fp, vec![5, 3]).is_irreducible());
+        assert!(!Polynomial::new(&fp, vec![5]).is_irreducible());
+        assert!(!Polynomial::zero(&fp).is_irreducible_ben_or());
+
+        // Over F2: x^2 + x + 1, x^3 + x + 1, x^8 + x^4 + x^3 + x + 1 (AES).
+        let f2 = field::Field::new(2);
+        for f in [
+            Polynomial::new(&f2, vec![1, 1, 1]),
+            Polynomial::new(&f2, vec![1, 1, 0, 1]),
+            Polynomial::new(&f2, vec![1, 1, 0, 1, 1, 0, 0, 0, 1]),
+        ]
+        .iter()
+        {
+            assert!(f.is_irreducible());
+            assert!(f.is_irreducible_ben_or());
+        }
+        // x^4 + x^2 + 1 = (x^2 + x + 1)^2
+        let sq = Polynomial::new(&f2, vec![1, 0, 1, 0, 1]);
+        assert!(!sq.is_irreducible());
+        assert!(!sq.is_irreducible_ben_or());
+
+        // Both tests agree on all monic cubics over F5.
+        let fp5 = field::Field::new(5);
+        let mut count = 0;
+        for c in 0..125 {
+            let f = Polynomial::new(&fp5, vec![c % 5, c / 5 % 5, c / 25, 1]);
+            assert_eq!(f.is_irreducible(), f.is_irreducible_ben_or());
+            if f.is_irreducible() {
+                count += 1;
+            }
+        }
+        // (5^3 - 5) / 3 monic irreducible cubics.
+        assert_eq!(count, 40);
+    }
+}
@@ -3,7 +3,6 @@
 
 #[allow(non_snake_case)]
 #[cfg(test)]
-
 pub mod tests {
     use curves::clockcurve;
 
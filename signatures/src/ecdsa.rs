@@ -5,7 +5,7 @@ use algebra::arith;
 use curves::clockcurve;
 use subgroups::subgroup;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ECDSA {
     pub group: subgroup::SubGroup,
 }
@@ -5,7 +5,7 @@ use algebra::arith;
 use curves::clockcurve;
 use subgroups::subgroup;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Schnorr {
     pub group: subgroup::SubGroup,
 }
@@ -107,7 +107,7 @@ impl Schnorr {
             .group
             .curve
             .scalar_add(r, self.group.curve.scalar_mul(pubkey, e));
-        (s1 == s2)
+        s1 == s2
     }
 
     #[allow(clippy::too_many_arguments)]
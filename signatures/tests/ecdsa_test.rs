@@ -3,7 +3,6 @@
 
 #[allow(non_snake_case)]
 #[cfg(test)]
-
 pub mod tests {
     use algebra::arith;
     use signatures::ecdsa;
@@ -21,7 +20,7 @@ pub mod tests {
 
         let pubkey = signature.pubkey(private);
         let verify = signature.verify(message, pubkey, r, s);
-        assert_eq!(verify, true);
+        assert!(verify);
     }
 
     #[test]
@@ -3,7 +3,6 @@
 
 #[allow(non_snake_case)]
 #[cfg(test)]
-
 pub mod tests {
     use algebra::arith;
     use signatures::schnorr;
@@ -21,7 +20,7 @@ pub mod tests {
 
         let pubkey = signature.pubkey(private);
         let verify = signature.verify(message, pubkey, r, s);
-        assert_eq!(verify, true);
+        assert!(verify);
     }
 
     #[test]
@@ -40,7 +39,7 @@ pub mod tests {
 
         let pubkey1 = signature.pubkey(private1);
         let verify1 = signature.verify(message1, pubkey1, r1, s1);
-        assert_eq!(verify1, true);
+        assert!(verify1);
 
         let (r2, s2) = signature.sign(message2, private2, randomk2);
         println!(
@@ -51,10 +50,10 @@ pub mod tests {
 
         let pubkey2 = signature.pubkey(private2);
         let verify2 = signature.verify(message2, pubkey2, r2, s2);
-        assert_eq!(verify2, true);
+        assert!(verify2);
 
         let verify = signature.batch_verify(message1, pubkey1, r1, s1, message2, pubkey2, r2, s2);
-        assert_eq!(verify, true);
+        assert!(verify);
     }
 
     #[test]
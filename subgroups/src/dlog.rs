@@ -0,0 +1,327 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::collections::HashMap;
+
+use algebra::{arith, crt, factor, gcd};
+
+use crate::group::{element_hash, Group};
+
+/// Attempts with a fresh partition before the random walks give up.
+const ATTEMPTS: u64 = 16;
+
+/// Solves h = k * g with baby-step giant-step.
+///
+/// ```text
+/// Returns the least k in [0, order) or None if h is not a multiple of g.
+/// order is the order of g, or any multiple of it.
+/// ```
+///
+/// ```text
+/// Algorithm:
+/// m = ceil(sqrt(order)), k = i * m + j, 0 <= i, j < m
+/// baby steps:  store j * g -> j
+/// giant steps: h - i * (m * g), look it up in the table
+/// O(sqrt(order)) time and memory.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use curves::clockcurve;
+/// use subgroups::dlog;
+///
+/// fn main() {
+///     let curve = clockcurve::ClockCurve::default();
+///     let h = curve.scalar_basemul(13);
+///     let k = dlog::bsgs(&curve, &curve.base, &h, 32);
+///     println!("{:?}", k);
+/// }
+/// ```
+pub fn bsgs<G: Group>(group: &G, g: &G::Element, h: &G::Element, order: u64) -> Option<u64> {
+    let m = ceil_sqrt(order);
+    let mut table = HashMap::with_capacity(m as usize);
+    let mut e = group.identity();
+    for j in 0..m {
+        table.entry(e.clone()).or_insert(j);
+        e = group.add(&e, g);
+    }
+
+    // e = m * g
+    let giant = group.neg(&e);
+    let mut gamma = h.clone();
+    for i in 0..m {
+        if let Some(j) = table.get(&gamma) {
+            return Some(i * m + j);
+        }
+        gamma = group.add(&gamma, &giant);
+    }
+    None
+}
+
+/// Solves h = k * g with Pollard's rho for logarithms.
+///
+/// ```text
+/// Returns k in [0, order) or None, order must be the order of g.
+/// ```
+///
+/// ```text
+/// Algorithm:
+/// Walk on x = a * g + b * h, the next step depends on the class of x:
+/// S0: x + g  (a + 1, b)
+/// S1: 2x     (2a, 2b)
+/// S2: x + h  (a, b + 1)
+/// Floyd's cycle finding, x(i) = x(2i):
+/// a(i) * g + b(i) * h = a(2i) * g + b(2i) * h
+/// (b(2i) - b(i)) * k = a(i) - a(2i) (mod order)
+/// The congruence has gcd(b(2i) - b(i), order) solutions, each is checked.
+/// O(sqrt(order)) time, O(1) memory.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use subgroups::dlog;
+/// use subgroups::group::{Group, MultiplicativeGroup};
+///
+/// fn main() {
+///     // 4 has order 500333 in Z_1000667^*
+///     let zp = MultiplicativeGroup::new(1000667);
+///     let h = zp.mul(&4, 123456);
+///     let k = dlog::pollard_rho(&zp, &4, &h, 500333);
+///     println!("{:?}", k);
+/// }
+/// ```
+pub fn pollard_rho<G: Group>(group: &G, g: &G::Element, h: &G::Element, order: u64) -> Option<u64> {
+    let n = order;
+    let max_steps = 16 * ceil_sqrt(n) + 64;
+    let step = |x: &G::Element, a: u64, b: u64, salt: u64| match element_hash(&(x, salt)) % 3 {
+        0 => (group.add(x, g), (a + 1) % n, b),
+        1 => (group.add(x, x), mul_mod(2, a, n), mul_mod(2, b, n)),
+        _ => (group.add(x, h), a, (b + 1) % n),
+    };
+
+    for salt in 0..ATTEMPTS {
+        let (a0, b0) = ((salt + 1) % n, 1 % n);
+        let x0 = group.add(&group.mul(g, a0), &group.mul(h, b0));
+        let (mut x, mut a, mut b) = (x0.clone(), a0, b0);
+        let (mut xx, mut aa, mut bb) = (x0, a0, b0);
+
+        for _ in 0..max_steps {
+            let t = step(&x, a, b, salt);
+            x = t.0;
+            a = t.1;
+            b = t.2;
+            let t = step(&xx, aa, bb, salt);
+            let t = step(&t.0, t.1, t.2, salt);
+            xx = t.0;
+            aa = t.1;
+            bb = t.2;
+            if x == xx {
+                let r = sub_mod(bb, b, n);
+                let s = sub_mod(a, aa, n);
+                if let Some(k) = solve_congruence(group, g, h, r, s, n) {
+                    return Some(k);
+                }
+                break;
+            }
+        }
+    }
+    None
+}
+
+/// Solves h = k * g for k in [lower, upper] with Pollard's kangaroo (lambda).
+///
+/// ```text
+/// Returns None if no such k is found.
+/// upper + w should stay below the order of g, otherwise the kangaroos
+/// may meet at k plus a multiple of the order, which is out of the interval.
+/// ```
+///
+/// ```text
+/// Algorithm:
+/// w = upper - lower, jumps are powers of two with mean about sqrt(w) / 2,
+/// the jump of a kangaroo at x depends only on x.
+/// tame: starts at upper * g, makes about 2 * sqrt(w) jumps, stops as a trap
+/// wild: starts at h, jumps until it lands on the trap or passes it
+/// Once the wild one lands on a spot visited by the tame one,
+/// they follow the same path, so the wild one ends in the trap:
+/// k + d(wild) = upper + d(tame)
+/// O(sqrt(w)) time, O(1) memory.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use subgroups::dlog;
+/// use subgroups::group::{Group, MultiplicativeGroup};
+///
+/// fn main() {
+///     let zp = MultiplicativeGroup::new(1000667);
+///     let h = zp.mul(&4, 300000);
+///     let k = dlog::kangaroo(&zp, &4, &h, 290000, 310000);
+///     println!("{:?}", k);
+/// }
+/// ```
+pub fn kangaroo<G: Group>(
+    group: &G,
+    g: &G::Element,
+    h: &G::Element,
+    lower: u64,
+    upper: u64,
+) -> Option<u64> {
+    assert!(lower <= upper, "empty interval");
+    let w = upper - lower;
+    let root = ceil_sqrt(w).max(1);
+
+    // Mean jump (2^k - 1) / k reaches sqrt(w) / 2.
+    let mut k = 1;
+    while k < 63 && ((1u64 << k) - 1) / k < root / 2 {
+        k += 1;
+    }
+    let jumps: Vec<(u64, G::Element)> = (0..k)
+        .map(|i| (1u64 << i, group.mul(g, 1u64 << i)))
+        .collect();
+    let trap_jumps = 2 * root;
+
+    for salt in 0..ATTEMPTS {
+        let jump = |x: &G::Element| &jumps[(element_hash(&(x, salt)) % k) as usize];
+
+        let mut tame = group.mul(g, upper);
+        let mut dt = 0u64;
+        for _ in 0..trap_jumps {
+            let (d, e) = jump(&tame);
+            tame = group.add(&tame, e);
+            dt += d;
+        }
+
+        let mut wild = h.clone();
+        let mut dw = 0u64;
+        while dw <= w + dt {
+            if wild == tame {
+                // In a small group the paths can also meet at an alias of k.
+                let x = upper + dt - dw;
+                if x >= lower && x <= upper && group.mul(g, x) == *h {
+                    return Some(x);
+                }
+                break;
+            }
+            let (d, e) = jump(&wild);
+            wild = group.add(&wild, e);
+            dw += d;
+        }
+    }
+    None
+}
+
+/// Solves h = k * g with Pohlig-Hellman, fast when the order is smooth.
+///
+/// ```text
+/// Returns k in [0, order) or None, order must be the order of g.
+/// ```
+///
+/// ```text
+/// Algorithm:
+/// order = p1^e1 * ... * pr^er, factored with algebra::factor
+/// for every p^e:
+///     g' = (order / p^e) * g, h' = (order / p^e) * h, g' has order p^e
+///     k mod p^e = x0 + x1 * p + ... + x(e-1) * p^(e-1)
+///     gamma = p^(e-1) * g', of order p
+///     xi = dlog(gamma, p^(e-1-i) * (h' - (x0 + ... + x(i-1) * p^(i-1)) * g'))
+///     every xi is a DLP in a group of order p, solved with bsgs
+/// recombine the k mod p^e with algebra::crt
+/// The cost depends on the largest prime p, not on the order.
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use curves::clockcurve;
+/// use subgroups::dlog;
+///
+/// fn main() {
+///     let curve = clockcurve::ClockCurve::default();
+///     let h = curve.scalar_basemul(13);
+///     let k = dlog::pohlig_hellman(&curve, &curve.base, &h, 32);
+///     println!("{:?}", k);
+/// }
+/// ```
+pub fn pohlig_hellman<G: Group>(
+    group: &G,
+    g: &G::Element,
+    h: &G::Element,
+    order: u64,
+) -> Option<u64> {
+    let mut residues = Vec::new();
+    let mut moduli = Vec::new();
+    for (p, e) in factor::factorize(order) {
+        let pe = p.pow(e);
+        let cofactor = order / pe;
+        let gi = group.mul(g, cofactor);
+        let hi = group.mul(h, cofactor);
+        let gamma = group.mul(&gi, pe / p);
+
+        let mut x = 0u64;
+        let mut pk = 1u64;
+        for k in 0..e {
+            let rest = group.add(&hi, &group.neg(&group.mul(&gi, x)));
+            let hk = group.mul(&rest, pe / p / pk);
+            let dk = bsgs(group, &gamma, &hk, p)?;
+            x += dk * pk;
+            if k + 1 < e {
+                pk *= p;
+            }
+        }
+        residues.push(x as i128);
+        moduli.push(pe as i128);
+    }
+
+    let (k, _) = crt::crt(&residues, &moduli)?;
+    let k = k as u64;
+    if group.mul(g, k) == *h {
+        Some(k)
+    } else {
+        None
+    }
+}
+
+/// Solves r * k = s (mod n), keeps the k with k * g = h.
+fn solve_congruence<G: Group>(
+    group: &G,
+    g: &G::Element,
+    h: &G::Element,
+    r: u64,
+    s: u64,
+    n: u64,
+) -> Option<u64> {
+    if r == 0 {
+        return None;
+    }
+    let d = gcd::gcd(r, n);
+    if s % d != 0 {
+        return None;
+    }
+    let n1 = n / d;
+    let k0 = mul_mod(s / d, arith::mod_inv(r / d, n1), n1);
+    (0..d).map(|t| k0 + t * n1).find(|k| group.mul(g, *k) == *h)
+}
+
+fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    (a as u128 * b as u128 % n as u128) as u64
+}
+
+fn sub_mod(a: u64, b: u64, n: u64) -> u64 {
+    arith::mod_sub(a, b, n)
+}
+
+/// ceil(sqrt(n))
+fn ceil_sqrt(n: u64) -> u64 {
+    let mut m = (n as f64).sqrt() as u64;
+    while m > 0 && m as u128 * m as u128 >= n as u128 {
+        m -= 1;
+    }
+    while (m as u128 * m as u128) < n as u128 {
+        m += 1;
+    }
+    m
+}
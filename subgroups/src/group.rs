@@ -0,0 +1,126 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use algebra::arith;
+use curves::clockcurve;
+use fields::field;
+
+/// Finite abelian group, written additively.
+///
+/// ```text
+/// add: a + b
+/// neg: -a
+/// mul: k * a = a + a + ... + a
+/// For Z_p^* the group operation is the product, so
+/// add is a * b, neg is a^-1 and mul is a^k.
+/// ```
+pub trait Group {
+    type Element: Clone + Debug + Eq + Hash;
+
+    fn identity(&self) -> Self::Element;
+
+    fn add(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    fn neg(&self, a: &Self::Element) -> Self::Element;
+
+    /// Computes k * a with double-and-add.
+    fn mul(&self, a: &Self::Element, k: u64) -> Self::Element {
+        let mut res = self.identity();
+        for i in (0..64 - k.leading_zeros()).rev() {
+            res = self.add(&res, &res);
+            if (k >> i) & 1 == 1 {
+                res = self.add(&res, a);
+            }
+        }
+        res
+    }
+}
+
+/// Returns a hash of the element, used to partition the group
+/// into pseudo-random classes for the random walks.
+pub fn element_hash<E: Hash>(e: &E) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    e.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The points of a clock curve under point addition.
+impl Group for clockcurve::ClockCurve {
+    type Element = clockcurve::Point;
+
+    fn identity(&self) -> clockcurve::Point {
+        self.infinity
+    }
+
+    fn add(&self, a: &clockcurve::Point, b: &clockcurve::Point) -> clockcurve::Point {
+        self.scalar_add(*a, *b)
+    }
+
+    fn neg(&self, a: &clockcurve::Point) -> clockcurve::Point {
+        self.point_neg(*a)
+    }
+}
+
+/// The multiplicative group Z_p^* of integers modulo a prime p.
+///
+/// # Examples
+///
+/// ```rust
+/// use subgroups::group::{Group, MultiplicativeGroup};
+///
+/// fn main() {
+///     let zp = MultiplicativeGroup::new(37);
+///     println!("{}", zp.mul(&2, 36));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiplicativeGroup {
+    pub p: u64,
+}
+
+impl MultiplicativeGroup {
+    pub fn new(p: u64) -> Self {
+        assert!(p > 2);
+        MultiplicativeGroup { p }
+    }
+
+    /// Returns the least generator of Z_p^*, of order p - 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use subgroups::group::MultiplicativeGroup;
+    ///
+    /// fn main() {
+    ///     let zp = MultiplicativeGroup::new(37);
+    ///     println!("{}", zp.generator());
+    /// }
+    /// ```
+    pub fn generator(&self) -> u64 {
+        field::Field::new(self.p).primitive_root()
+    }
+}
+
+impl Group for MultiplicativeGroup {
+    type Element = u64;
+
+    fn identity(&self) -> u64 {
+        1
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> u64 {
+        (*a as u128 * *b as u128 % self.p as u128) as u64
+    }
+
+    fn neg(&self, a: &u64) -> u64 {
+        arith::mod_inv(*a, self.p)
+    }
+
+    fn mul(&self, a: &u64, k: u64) -> u64 {
+        arith::mod_exp(*a as u128, k as u128, self.p as u128) as u64
+    }
+}
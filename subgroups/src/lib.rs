@@ -1,4 +1,6 @@
 // Copyright (c) BohuTANG
 // Code is licensed with BSD
 
+pub mod dlog;
+pub mod group;
 pub mod subgroup;
@@ -3,7 +3,7 @@
 
 use curves::clockcurve;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct SubGroup {
     pub infinity: clockcurve::Point,
     pub basepoint: clockcurve::Point,
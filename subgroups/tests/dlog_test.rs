@@ -0,0 +1,115 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use std::time::Instant;
+
+    use curves::{clockcurve, keys};
+    use subgroups::dlog;
+    use subgroups::group::{Group, MultiplicativeGroup};
+    use subgroups::subgroup;
+
+    #[test]
+    fn subgroups_dlog_bsgs_test() {
+        let curve = clockcurve::ClockCurve::default();
+        let start = Instant::now();
+        for k in 1..32 {
+            let privatekey = keys::PrivateKey::new(k);
+            let h = privatekey.publickey().point;
+            let x = dlog::bsgs(&curve, &curve.base, &h, 32).unwrap();
+            assert_eq!(x as i8, privatekey.key);
+        }
+        println!("bsgs: 31 clock curve keys in {:?}", start.elapsed());
+
+        // Not in the subgroup of order 4 spanned by (1, 0).
+        let g = clockcurve::Point { x: 1, y: 0 };
+        assert_eq!(dlog::bsgs(&curve, &g, &curve.base, 4), None);
+
+        let zp = MultiplicativeGroup::new(1000667);
+        let h = zp.mul(&4, 123456);
+        assert_eq!(dlog::bsgs(&zp, &4, &h, 500333), Some(123456));
+        assert_eq!(dlog::bsgs(&zp, &4, &1, 500333), Some(0));
+        // 1000666 = -1 is not a power of 4.
+        assert_eq!(dlog::bsgs(&zp, &4, &1000666, 500333), None);
+    }
+
+    #[test]
+    fn subgroups_dlog_pollard_rho_test() {
+        let curve = clockcurve::ClockCurve::default();
+        let start = Instant::now();
+        for k in 1..32 {
+            let privatekey = keys::PrivateKey::new(k);
+            let h = privatekey.publickey().point;
+            let x = dlog::pollard_rho(&curve, &curve.base, &h, 32).unwrap();
+            assert_eq!(x as i8, privatekey.key);
+        }
+        println!("rho: 31 clock curve keys in {:?}", start.elapsed());
+
+        // 4 has prime order 500333 in Z_1000667^*, 1000667 = 2 * 500333 + 1.
+        let zp = MultiplicativeGroup::new(1000667);
+        for k in [1u64, 2, 123456, 500332].iter() {
+            let h = zp.mul(&4, *k);
+            assert_eq!(dlog::pollard_rho(&zp, &4, &h, 500333), Some(*k));
+        }
+    }
+
+    #[test]
+    fn subgroups_dlog_kangaroo_test() {
+        // The interval must be well below the group order 32,
+        // otherwise the kangaroos meet at aliases of the key.
+        let curve = clockcurve::ClockCurve::default();
+        for k in 1..16 {
+            let privatekey = keys::PrivateKey::new(k);
+            let h = privatekey.publickey().point;
+            let x = dlog::kangaroo(&curve, &curve.base, &h, 0, 15).unwrap();
+            assert_eq!(x as i8, privatekey.key);
+        }
+
+        let zp = MultiplicativeGroup::new(1000667);
+        let start = Instant::now();
+        for k in [290000u64, 300000, 309999, 310000].iter() {
+            let h = zp.mul(&4, *k);
+            assert_eq!(dlog::kangaroo(&zp, &4, &h, 290000, 310000), Some(*k));
+        }
+        println!(
+            "kangaroo: 4 logs in an interval of 20000 in {:?}",
+            start.elapsed()
+        );
+    }
+
+    #[test]
+    fn subgroups_dlog_pohlig_hellman_test() {
+        // The clock curve group has order 32 = 2^5, every key falls
+        // to five DLPs in the group of order 2.
+        let sub = subgroup::SubGroup::default();
+        let order = sub.order() as u64;
+        assert_eq!(order, 32);
+        let curve = clockcurve::ClockCurve::default();
+        for k in 1..32 {
+            let privatekey = keys::PrivateKey::new(k);
+            let publickey = privatekey.publickey();
+            let x = dlog::pohlig_hellman(&curve, &curve.base, &publickey.point, order).unwrap();
+            assert_eq!(x as i8, privatekey.key);
+        }
+
+        // Order 4 subgroup spanned by (1, 0).
+        let g = clockcurve::Point { x: 1, y: 0 };
+        let h = curve.scalar_mul(g, 3);
+        assert_eq!(dlog::pohlig_hellman(&curve, &g, &h, 4), Some(3));
+        assert_eq!(dlog::pohlig_hellman(&curve, &g, &curve.base, 4), None);
+
+        // p - 1 = 2^3 * 3 * 5 * 7 * 11 * 13 * 17 * 19 * 23, 58 is a primitive root.
+        let p = 892371481u64;
+        let zp = MultiplicativeGroup::new(p);
+        let start = Instant::now();
+        for k in [1u64, 2, 123456789, p - 2].iter() {
+            let h = zp.mul(&58, *k);
+            assert_eq!(dlog::pohlig_hellman(&zp, &58, &h, p - 1), Some(*k));
+        }
+        println!(
+            "pohlig-hellman: 4 logs mod a 30-bit prime in {:?}",
+            start.elapsed()
+        );
+    }
+}
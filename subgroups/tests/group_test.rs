@@ -0,0 +1,25 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+#[cfg(test)]
+pub mod tests {
+    use subgroups::group::{Group, MultiplicativeGroup};
+
+    #[test]
+    fn subgroups_group_generator_test() {
+        assert_eq!(MultiplicativeGroup::new(7).generator(), 3);
+        assert_eq!(MultiplicativeGroup::new(37).generator(), 2);
+        // 1000667 = 2 * 500333 + 1, 2 is a non-residue.
+        assert_eq!(MultiplicativeGroup::new(1000667).generator(), 2);
+
+        // p - 1 = 2^3 * 3 * 5 * 7 * 11 * 13 * 17 * 19 * 23, 58 is the least primitive root.
+        let p = 892371481u64;
+        let zp = MultiplicativeGroup::new(p);
+        let g = zp.generator();
+        assert_eq!(g, 58);
+        for q in [2u64, 3, 5, 7, 11, 13, 17, 19, 23].iter() {
+            assert_ne!(zp.mul(&g, (p - 1) / q), zp.identity());
+        }
+        assert_eq!(zp.mul(&g, p - 1), zp.identity());
+    }
+}
@@ -5,9 +5,7 @@ use curves::clockcurve;
 
 #[derive(Debug)]
 pub struct SPAKE2 {
-    password: i8,
     secret: i8,
-    random: i8,
     secret_pubkey: clockcurve::Point,
     pub password_pubkey: clockcurve::Point,
     pub curve: clockcurve::ClockCurve,
@@ -36,9 +34,7 @@ impl SPAKE2 {
         let rand_pubkey = curv.scalar_basemul(rnd);
         let pwd_pubkey = curv.scalar_mul(rand_pubkey, pwd);
         SPAKE2 {
-            password: pwd,
             secret: sec,
-            random: rnd,
             secret_pubkey: sec_pubkey,
             password_pubkey: pwd_pubkey,
             curve: curv,